@@ -0,0 +1,952 @@
+//! Instruction builders for every vault program instruction.
+//!
+//! Each builder takes the Anchor-generated argument struct from
+//! `solana_core_contracts::instruction` and derives all PDAs itself. Builders
+//! for responses and cancellations take the pending record they settle, since
+//! the accounts they touch are keyed by fields stored there.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::token_2022;
+use solana_core_contracts::constants::NATIVE_TOKEN_ADDRESS;
+use solana_core_contracts::{accounts, pda, ID};
+
+pub use solana_core_contracts::instruction as args;
+
+use crate::state::{
+    PendingErc20Deposit, PendingErc20Withdrawal, PendingNativeDeposit, PendingNativeWithdrawal,
+    RequestRecord,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn process_deposit(args: args::ProcessDeposit) -> Instruction {
+    build(accounts::ProcessVault {}, args)
+}
+
+pub fn process_withdraw(args: args::ProcessWithdraw) -> Instruction {
+    build(accounts::ProcessVault {}, args)
+}
+
+/// Simulate and read the `SignRequestPreview` from the return data
+pub fn preview_deposit_erc20(args: args::PreviewDepositErc20) -> Instruction {
+    build(
+        accounts::PreviewVault {
+            config: pda::vault_config().0,
+        },
+        args,
+    )
+}
+
+/// Simulate and read the `SignRequestPreview` from the return data
+pub fn preview_withdraw_erc20(args: args::PreviewWithdrawErc20) -> Instruction {
+    build(
+        accounts::PreviewWithdrawErc20 {
+            config: pda::vault_config().0,
+            token_config: pda::token_config(args.tx_params.chain_id, &args.erc20_address).0,
+        },
+        args,
+    )
+}
+
+fn sign_vault_transaction(
+    authority: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+) -> accounts::SignVaultTransaction {
+    accounts::SignVaultTransaction {
+        authority,
+        config: pda::vault_config().0,
+        requester: pda::vault_authority(&authority).0,
+        fee_payer,
+        chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+        chain_signatures_program,
+        system_program: system_program::ID,
+        instructions: Some(sysvar::instructions::ID),
+    }
+}
+
+pub fn sign_deposit_transaction(
+    authority: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    args: args::SignDepositTransaction,
+) -> Instruction {
+    build(
+        sign_vault_transaction(authority, fee_payer, chain_signatures_program),
+        args,
+    )
+}
+
+pub fn sign_withdraw_transaction(
+    authority: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    args: args::SignWithdrawTransaction,
+) -> Instruction {
+    build(
+        sign_vault_transaction(authority, fee_payer, chain_signatures_program),
+        args,
+    )
+}
+
+pub fn initialize_config(authority: Pubkey, args: args::InitializeConfig) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            authority,
+            config: pda::vault_config().0,
+            program: ID,
+            program_data: pda::program_data().0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn update_config(authority: Pubkey, args: args::UpdateConfig) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            authority,
+            config: pda::vault_config().0,
+        },
+        args,
+    )
+}
+
+pub fn register_mpc_signer(authority: Pubkey, args: args::RegisterMpcSigner) -> Instruction {
+    build(
+        accounts::RegisterMpcSigner {
+            authority,
+            config: pda::vault_config().0,
+            mpc_signer: pda::mpc_signer(args.key_version).0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn rotate_mpc_key(
+    authority: Pubkey,
+    current_key_version: u32,
+    args: args::RotateMpcKey,
+) -> Instruction {
+    build(
+        accounts::RotateMpcKey {
+            authority,
+            config: pda::vault_config().0,
+            current_signer: pda::mpc_signer(current_key_version).0,
+            new_signer: pda::mpc_signer(args.new_key_version).0,
+        },
+        args,
+    )
+}
+
+pub fn revoke_mpc_signer(authority: Pubkey, args: args::RevokeMpcSigner) -> Instruction {
+    build(
+        accounts::RevokeMpcSigner {
+            authority,
+            config: pda::vault_config().0,
+            mpc_signer: pda::mpc_signer(args.key_version).0,
+        },
+        args,
+    )
+}
+
+/// `signer` is either the config authority or the guardian
+pub fn set_pause_flags(signer: Pubkey, args: args::SetPauseFlags) -> Instruction {
+    build(
+        accounts::SetPauseFlags {
+            signer,
+            config: pda::vault_config().0,
+        },
+        args,
+    )
+}
+
+pub fn register_token(authority: Pubkey, args: args::RegisterToken) -> Instruction {
+    build(
+        accounts::RegisterToken {
+            authority,
+            config: pda::vault_config().0,
+            token_config: pda::token_config(args.chain_id, &args.erc20_address).0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn update_token(authority: Pubkey, args: args::UpdateToken) -> Instruction {
+    build(
+        accounts::UpdateToken {
+            authority,
+            config: pda::vault_config().0,
+            token_config: pda::token_config(args.chain_id, &args.erc20_address).0,
+        },
+        args,
+    )
+}
+
+pub fn set_chain_config(authority: Pubkey, args: args::SetChainConfig) -> Instruction {
+    build(
+        accounts::SetChainConfig {
+            authority,
+            config: pda::vault_config().0,
+            chain_config: pda::chain_config(args.chain_id).0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn sync_nonce_tracker(authority: Pubkey, args: args::SyncNonceTracker) -> Instruction {
+    build(
+        accounts::SyncNonceTracker {
+            authority,
+            config: pda::vault_config().0,
+            nonce_tracker: pda::nonce_tracker(args.chain_id, &args.path, args.key_version).0,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// A `wrapped` deposit is claimed as wrapped tokens, the requester needs a token account of
+/// the wrapped mint by then, see `create_wrapped_token_account`
+pub fn deposit_erc20(
+    payer: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    wrapped: bool,
+    args: args::DepositErc20,
+) -> Instruction {
+    let chain_id = args.tx_params.chain_id;
+
+    build(
+        accounts::DepositErc20 {
+            payer,
+            config: pda::vault_config().0,
+            token_config: pda::token_config(chain_id, &args.erc20_address).0,
+            chain_config: pda::chain_config(chain_id).0,
+            requester_pda: pda::vault_authority(&args.requester).0,
+            pending_deposit: pda::pending_erc20_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_erc20_balance(&args.requester, chain_id, &args.erc20_address).0,
+            wrapped_mint: wrapped.then(|| pda::wrapped_mint(chain_id, &args.erc20_address).0),
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `relayer` submits the MPC response and receives the relayer tip. The fee balance is
+/// only passed when the deposit carries a fee, the wrapped-mode accounts only for wrapped
+/// deposits, which mint into the requester's associated token account.
+pub fn claim_erc20(
+    relayer: Pubkey,
+    pending: &PendingErc20Deposit,
+    args: args::ClaimErc20,
+) -> Instruction {
+    let wrapped = |address: Pubkey| pending.wrapped.then_some(address);
+
+    build(
+        accounts::ClaimErc20 {
+            payer: relayer,
+            rent_payer: pending.payer,
+            config: pda::vault_config().0,
+            pending_deposit: pda::pending_erc20_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            mpc_signer: pda::mpc_signer(pending.key_version).0,
+            fee_balance: (pending.fee > 0)
+                .then(|| pda::fee_balance(pending.chain_id, &pending.erc20_address).0),
+            user_balance: pda::user_erc20_balance(
+                &pending.requester,
+                pending.chain_id,
+                &pending.erc20_address,
+            )
+            .0,
+            token_config: wrapped(pda::token_config(pending.chain_id, &pending.erc20_address).0),
+            wrapped_mint: wrapped(pda::wrapped_mint(pending.chain_id, &pending.erc20_address).0),
+            wrapped_token_account: wrapped(wrapped_token_account(
+                &pending.requester,
+                pending.chain_id,
+                &pending.erc20_address,
+            )),
+            token_program: wrapped(token_2022::ID),
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn cancel_expired_deposit(
+    pending: &PendingErc20Deposit,
+    args: args::CancelExpiredDeposit,
+) -> Instruction {
+    build(
+        accounts::CancelExpiredDeposit {
+            requester: pending.requester,
+            payer: pending.payer,
+            pending_deposit: pda::pending_erc20_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+        },
+        args,
+    )
+}
+
+/// `withdrawal_root_path` and `key_version` are the vault config's root path and current key,
+/// they key the nonce tracker and the withdrawal signer
+/// Withdraws `owner`'s balance, a delegate `authority` spends its allowance. A `wrapped`
+/// withdrawal first burns the amount from `owner`'s associated token account
+pub fn withdraw_erc20(
+    authority: Pubkey,
+    owner: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    withdrawal_root_path: &str,
+    key_version: u32,
+    wrapped: bool,
+    args: args::WithdrawErc20,
+) -> Instruction {
+    build(
+        accounts::WithdrawErc20 {
+            authority,
+            owner,
+            config: pda::vault_config().0,
+            current_signer: pda::mpc_signer(key_version).0,
+            token_config: pda::token_config(args.tx_params.chain_id, &args.erc20_address).0,
+            chain_config: pda::chain_config(args.tx_params.chain_id).0,
+            requester: pda::global_vault_authority().0,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            nonce_tracker: pda::nonce_tracker(
+                args.tx_params.chain_id,
+                withdrawal_root_path,
+                key_version,
+            )
+            .0,
+            token_rate_limit: pda::withdrawal_rate_limit(
+                args.tx_params.chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                args.tx_params.chain_id,
+                &args.erc20_address,
+                &owner,
+            )
+            .0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_erc20_balance(
+                &owner,
+                args.source_chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            allowance: (owner != authority).then(|| {
+                pda::withdrawal_allowance(
+                    &owner,
+                    &authority,
+                    args.source_chain_id,
+                    &args.erc20_address,
+                )
+                .0
+            }),
+            wrapped_mint: wrapped
+                .then(|| pda::wrapped_mint(args.source_chain_id, &args.erc20_address).0),
+            wrapped_token_account: wrapped
+                .then(|| wrapped_token_account(&owner, args.source_chain_id, &args.erc20_address)),
+            token_program: wrapped.then_some(token_2022::ID),
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `relayer` submits the MPC response and receives the relayer tip. `args.request_id` is
+/// the request that responded, either `pending.request_id` or one of its fee bumps.
+pub fn complete_withdraw_erc20(
+    relayer: Pubkey,
+    pending: &PendingErc20Withdrawal,
+    args: args::CompleteWithdrawErc20,
+) -> Instruction {
+    build(
+        accounts::CompleteWithdrawErc20 {
+            payer: relayer,
+            rent_payer: pending.payer,
+            config: pda::vault_config().0,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&pending.request_id).0,
+            request_record: pda::request_record(&pending.request_id).0,
+            mpc_signer: pda::mpc_signer(pending.key_version).0,
+            fee_balance: (pending.fee > 0)
+                .then(|| pda::fee_balance(pending.source_chain_id, &pending.erc20_address).0),
+            user_balance: pda::user_erc20_balance(
+                &pending.requester,
+                pending.source_chain_id,
+                &pending.erc20_address,
+            )
+            .0,
+            token_rate_limit: pda::withdrawal_rate_limit(pending.chain_id, &pending.erc20_address)
+                .0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                pending.chain_id,
+                &pending.erc20_address,
+                &pending.requester,
+            )
+            .0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn bump_withdrawal_fee(
+    authority: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    args: args::BumpWithdrawalFee,
+) -> Instruction {
+    build(
+        accounts::BumpWithdrawalFee {
+            authority,
+            config: pda::vault_config().0,
+            requester: pda::global_vault_authority().0,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            chain_config: pda::chain_config(args.tx_params.chain_id).0,
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `withdrawal_root_path` and `key_version` are the vault config's root path and current key,
+/// they key the nonce tracker and the withdrawal signer
+pub fn execute_queued_withdrawal(
+    payer: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    withdrawal_root_path: &str,
+    key_version: u32,
+    args: args::ExecuteQueuedWithdrawal,
+) -> Instruction {
+    let chain_id = args.tx_params.chain_id;
+
+    build(
+        accounts::ExecuteQueuedWithdrawal {
+            payer,
+            config: pda::vault_config().0,
+            current_signer: pda::mpc_signer(key_version).0,
+            requester: pda::global_vault_authority().0,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            chain_config: pda::chain_config(chain_id).0,
+            nonce_tracker: pda::nonce_tracker(chain_id, withdrawal_root_path, key_version).0,
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn cancel_queued_withdrawal(
+    guardian: Pubkey,
+    pending: &PendingErc20Withdrawal,
+    args: args::CancelQueuedWithdrawal,
+) -> Instruction {
+    build(
+        accounts::CancelQueuedWithdrawal {
+            guardian,
+            config: pda::vault_config().0,
+            rent_payer: pending.payer,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_erc20_balance(
+                &pending.requester,
+                pending.source_chain_id,
+                &pending.erc20_address,
+            )
+            .0,
+            token_rate_limit: pda::withdrawal_rate_limit(pending.chain_id, &pending.erc20_address)
+                .0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                pending.chain_id,
+                &pending.erc20_address,
+                &pending.requester,
+            )
+            .0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// Sends `owner`'s balance, a delegate `authority` spends its allowance
+pub fn transfer_erc20_balance(
+    authority: Pubkey,
+    owner: Pubkey,
+    args: args::TransferErc20Balance,
+) -> Instruction {
+    build(
+        accounts::TransferErc20Balance {
+            authority,
+            owner,
+            config: pda::vault_config().0,
+            sender_balance: pda::user_erc20_balance(&owner, args.chain_id, &args.erc20_address).0,
+            allowance: (owner != authority).then(|| {
+                pda::withdrawal_allowance(&owner, &authority, args.chain_id, &args.erc20_address).0
+            }),
+            recipient_balance: pda::user_erc20_balance(
+                &args.recipient,
+                args.chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `fee_authority` is the one in the vault config
+pub fn collect_fees(fee_authority: Pubkey, args: args::CollectFees) -> Instruction {
+    build(
+        accounts::CollectFees {
+            fee_authority,
+            config: pda::vault_config().0,
+            fee_balance: pda::fee_balance(args.chain_id, &args.erc20_address).0,
+            recipient_balance: pda::user_erc20_balance(
+                &fee_authority,
+                args.chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn approve_withdrawal(authority: Pubkey, args: args::ApproveWithdrawal) -> Instruction {
+    build(
+        accounts::ApproveWithdrawal {
+            authority,
+            allowance: pda::withdrawal_allowance(
+                &authority,
+                &args.delegate,
+                args.chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn revoke_withdrawal_allowance(
+    authority: Pubkey,
+    args: args::RevokeWithdrawalAllowance,
+) -> Instruction {
+    build(
+        accounts::RevokeWithdrawalAllowance {
+            authority,
+            allowance: pda::withdrawal_allowance(
+                &authority,
+                &args.delegate,
+                args.chain_id,
+                &args.erc20_address,
+            )
+            .0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn create_wrapped_mint(authority: Pubkey, args: args::CreateWrappedMint) -> Instruction {
+    build(
+        accounts::CreateWrappedMint {
+            authority,
+            config: pda::vault_config().0,
+            token_config: pda::token_config(args.chain_id, &args.erc20_address).0,
+            wrapped_mint: pda::wrapped_mint(args.chain_id, &args.erc20_address).0,
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Associated Token-2022 account of `owner` for the wrapped mint of `erc20_address`
+pub fn wrapped_token_account(owner: &Pubkey, chain_id: u64, erc20_address: &[u8; 20]) -> Pubkey {
+    get_associated_token_address_with_program_id(
+        owner,
+        &pda::wrapped_mint(chain_id, erc20_address).0,
+        &token_2022::ID,
+    )
+}
+
+/// Creates `owner`'s associated token account of the wrapped mint if it does not exist yet
+pub fn create_wrapped_token_account(
+    payer: Pubkey,
+    owner: Pubkey,
+    chain_id: u64,
+    erc20_address: &[u8; 20],
+) -> Instruction {
+    create_associated_token_account_idempotent(
+        &payer,
+        &owner,
+        &pda::wrapped_mint(chain_id, erc20_address).0,
+        &token_2022::ID,
+    )
+}
+
+pub fn wrap_erc20_balance(authority: Pubkey, args: args::WrapErc20Balance) -> Instruction {
+    build(
+        accounts::WrapErc20Balance {
+            authority,
+            config: pda::vault_config().0,
+            user_balance: pda::user_erc20_balance(&authority, args.chain_id, &args.erc20_address).0,
+            token_config: pda::token_config(args.chain_id, &args.erc20_address).0,
+            wrapped_mint: pda::wrapped_mint(args.chain_id, &args.erc20_address).0,
+            user_token_account: wrapped_token_account(
+                &authority,
+                args.chain_id,
+                &args.erc20_address,
+            ),
+            token_program: token_2022::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// Burns from the holder's associated token account, use the program's accounts directly to
+/// unwrap from any other account
+pub fn unwrap_erc20_balance(authority: Pubkey, args: args::UnwrapErc20Balance) -> Instruction {
+    build(
+        accounts::UnwrapErc20Balance {
+            authority,
+            user_balance: pda::user_erc20_balance(&authority, args.chain_id, &args.erc20_address).0,
+            token_config: pda::token_config(args.chain_id, &args.erc20_address).0,
+            wrapped_mint: pda::wrapped_mint(args.chain_id, &args.erc20_address).0,
+            user_token_account: wrapped_token_account(
+                &authority,
+                args.chain_id,
+                &args.erc20_address,
+            ),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn refund_expired_withdrawal(
+    pending: &PendingErc20Withdrawal,
+    args: args::RefundExpiredWithdrawal,
+) -> Instruction {
+    build(
+        accounts::RefundExpiredWithdrawal {
+            requester: pending.requester,
+            rent_payer: pending.payer,
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_erc20_balance(
+                &pending.requester,
+                pending.source_chain_id,
+                &pending.erc20_address,
+            )
+            .0,
+            token_rate_limit: pda::withdrawal_rate_limit(pending.chain_id, &pending.erc20_address)
+                .0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                pending.chain_id,
+                &pending.erc20_address,
+                &pending.requester,
+            )
+            .0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn record_signed_erc20_withdrawal(args: args::RecordSignedErc20Withdrawal) -> Instruction {
+    build(
+        accounts::RecordSignedErc20Withdrawal {
+            pending_withdrawal: pda::pending_erc20_withdrawal(&args.request_id).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn deposit_native(
+    payer: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    args: args::DepositNative,
+) -> Instruction {
+    let chain_id = args.tx_params.chain_id;
+
+    build(
+        accounts::DepositNative {
+            payer,
+            config: pda::vault_config().0,
+            token_config: pda::token_config(chain_id, &NATIVE_TOKEN_ADDRESS).0,
+            chain_config: pda::chain_config(chain_id).0,
+            requester_pda: pda::vault_authority(&args.requester).0,
+            pending_deposit: pda::pending_native_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_native_balance(&args.requester, chain_id).0,
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `relayer` submits the MPC response and receives the relayer tip
+pub fn claim_native(
+    relayer: Pubkey,
+    pending: &PendingNativeDeposit,
+    args: args::ClaimNative,
+) -> Instruction {
+    build(
+        accounts::ClaimNative {
+            payer: relayer,
+            rent_payer: pending.payer,
+            config: pda::vault_config().0,
+            pending_deposit: pda::pending_native_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            mpc_signer: pda::mpc_signer(pending.key_version).0,
+            user_balance: pda::user_native_balance(&pending.requester, pending.chain_id).0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn cancel_expired_native_deposit(
+    pending: &PendingNativeDeposit,
+    args: args::CancelExpiredNativeDeposit,
+) -> Instruction {
+    build(
+        accounts::CancelExpiredNativeDeposit {
+            requester: pending.requester,
+            payer: pending.payer,
+            pending_deposit: pda::pending_native_deposit(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+        },
+        args,
+    )
+}
+
+/// `withdrawal_root_path` and `key_version` are the vault config's root path and current key,
+/// they key the nonce tracker and the withdrawal signer
+pub fn withdraw_native(
+    authority: Pubkey,
+    fee_payer: Option<Pubkey>,
+    chain_signatures_program: Pubkey,
+    withdrawal_root_path: &str,
+    key_version: u32,
+    args: args::WithdrawNative,
+) -> Instruction {
+    let chain_id = args.tx_params.chain_id;
+
+    build(
+        accounts::WithdrawNative {
+            authority,
+            config: pda::vault_config().0,
+            current_signer: pda::mpc_signer(key_version).0,
+            token_config: pda::token_config(chain_id, &NATIVE_TOKEN_ADDRESS).0,
+            chain_config: pda::chain_config(chain_id).0,
+            requester: pda::global_vault_authority().0,
+            pending_withdrawal: pda::pending_native_withdrawal(&args.request_id).0,
+            nonce_tracker: pda::nonce_tracker(chain_id, withdrawal_root_path, key_version).0,
+            token_rate_limit: pda::withdrawal_rate_limit(chain_id, &NATIVE_TOKEN_ADDRESS).0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                chain_id,
+                &NATIVE_TOKEN_ADDRESS,
+                &authority,
+            )
+            .0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_native_balance(&authority, chain_id).0,
+            fee_payer,
+            chain_signatures_state: pda::chain_signatures_state(&chain_signatures_program).0,
+            chain_signatures_event_authority: pda::chain_signatures_event_authority(
+                &chain_signatures_program,
+            )
+            .0,
+            chain_signatures_program,
+            system_program: system_program::ID,
+            instructions: Some(sysvar::instructions::ID),
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `relayer` submits the MPC response and receives the relayer tip
+pub fn complete_withdraw_native(
+    relayer: Pubkey,
+    pending: &PendingNativeWithdrawal,
+    args: args::CompleteWithdrawNative,
+) -> Instruction {
+    build(
+        accounts::CompleteWithdrawNative {
+            payer: relayer,
+            rent_payer: pending.payer,
+            config: pda::vault_config().0,
+            pending_withdrawal: pda::pending_native_withdrawal(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            mpc_signer: pda::mpc_signer(pending.key_version).0,
+            user_balance: pda::user_native_balance(&pending.requester, pending.chain_id).0,
+            token_rate_limit: pda::withdrawal_rate_limit(pending.chain_id, &NATIVE_TOKEN_ADDRESS).0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                pending.chain_id,
+                &NATIVE_TOKEN_ADDRESS,
+                &pending.requester,
+            )
+            .0,
+            system_program: system_program::ID,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn refund_expired_native_withdrawal(
+    pending: &PendingNativeWithdrawal,
+    args: args::RefundExpiredNativeWithdrawal,
+) -> Instruction {
+    build(
+        accounts::RefundExpiredNativeWithdrawal {
+            requester: pending.requester,
+            rent_payer: pending.payer,
+            pending_withdrawal: pda::pending_native_withdrawal(&args.request_id).0,
+            request_record: pda::request_record(&args.request_id).0,
+            user_balance: pda::user_native_balance(&pending.requester, pending.chain_id).0,
+            token_rate_limit: pda::withdrawal_rate_limit(pending.chain_id, &NATIVE_TOKEN_ADDRESS).0,
+            user_rate_limit: pda::user_withdrawal_rate_limit(
+                pending.chain_id,
+                &NATIVE_TOKEN_ADDRESS,
+                &pending.requester,
+            )
+            .0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+/// `signer` is the request's requester or the vault authority
+pub fn compress_request_record(
+    signer: Pubkey,
+    record: &RequestRecord,
+    args: args::CompressRequestRecord,
+) -> Instruction {
+    build(
+        accounts::CompressRequestRecord {
+            signer,
+            config: pda::vault_config().0,
+            rent_payer: record.payer,
+            request_record: pda::request_record(&args.request_id).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
+
+pub fn record_signed_native_withdrawal(args: args::RecordSignedNativeWithdrawal) -> Instruction {
+    build(
+        accounts::RecordSignedNativeWithdrawal {
+            pending_withdrawal: pda::pending_native_withdrawal(&args.request_id).0,
+            event_authority: pda::event_authority().0,
+            program: ID,
+        },
+        args,
+    )
+}
@@ -0,0 +1,41 @@
+//! Rust client for the vault program.
+//!
+//! PDA derivation, EVM transaction building and request ids are re-exported
+//! from the program crate rather than reimplemented, so a client can never
+//! compute a different request id than the program does.
+
+use anchor_lang::prelude::Pubkey;
+
+pub mod instructions;
+pub mod state;
+
+pub use solana_core_contracts::signing::{
+    evm_request_id, generate_sign_respond_request_id, hash_message,
+};
+pub use solana_core_contracts::{evm, pda, signing, ID as PROGRAM_ID};
+
+/// Request id of a deposit signed under the requester's own vault authority
+pub fn deposit_request_id(requester: &Pubkey, rlp_encoded_tx: &[u8], key_version: u32) -> [u8; 32] {
+    let (vault_authority, _) = pda::vault_authority(requester);
+    evm_request_id(
+        &vault_authority,
+        rlp_encoded_tx,
+        key_version,
+        &requester.to_string(),
+    )
+}
+
+/// Request id of a withdrawal signed under the global vault authority
+pub fn withdrawal_request_id(
+    rlp_encoded_tx: &[u8],
+    key_version: u32,
+    withdrawal_root_path: &str,
+) -> [u8; 32] {
+    let (global_vault_authority, _) = pda::global_vault_authority();
+    evm_request_id(
+        &global_vault_authority,
+        rlp_encoded_tx,
+        key_version,
+        withdrawal_root_path,
+    )
+}
@@ -0,0 +1,77 @@
+//! Deserializers for the vault program accounts.
+
+use anchor_lang::{AccountDeserialize, Result};
+
+pub use solana_core_contracts::state::{
+    ChainConfig, ChainConfigParams, EvmTransactionParams, MpcSigner, NonceTracker, PauseFlags,
+    PendingErc20Deposit, PendingErc20Withdrawal, PendingNativeDeposit, PendingNativeWithdrawal,
+    RequestKind, RequestRecord, RequestStatus, RequestTombstone, SignRequestPreview, TokenConfig,
+    TokenConfigParams, UserErc20Balance, UserNativeBalance, VaultConfig, VaultConfigParams,
+    WithdrawalAllowance, WithdrawalRateLimit,
+};
+
+/// Deserializes raw account data, checking the Anchor discriminator
+pub fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    let mut data = data;
+    T::try_deserialize(&mut data)
+}
+
+pub fn vault_config(data: &[u8]) -> Result<VaultConfig> {
+    deserialize(data)
+}
+
+pub fn mpc_signer(data: &[u8]) -> Result<MpcSigner> {
+    deserialize(data)
+}
+
+pub fn token_config(data: &[u8]) -> Result<TokenConfig> {
+    deserialize(data)
+}
+
+pub fn chain_config(data: &[u8]) -> Result<ChainConfig> {
+    deserialize(data)
+}
+
+pub fn nonce_tracker(data: &[u8]) -> Result<NonceTracker> {
+    deserialize(data)
+}
+
+pub fn withdrawal_rate_limit(data: &[u8]) -> Result<WithdrawalRateLimit> {
+    deserialize(data)
+}
+
+pub fn withdrawal_allowance(data: &[u8]) -> Result<WithdrawalAllowance> {
+    deserialize(data)
+}
+
+pub fn pending_erc20_deposit(data: &[u8]) -> Result<PendingErc20Deposit> {
+    deserialize(data)
+}
+
+pub fn pending_erc20_withdrawal(data: &[u8]) -> Result<PendingErc20Withdrawal> {
+    deserialize(data)
+}
+
+pub fn user_erc20_balance(data: &[u8]) -> Result<UserErc20Balance> {
+    deserialize(data)
+}
+
+pub fn pending_native_deposit(data: &[u8]) -> Result<PendingNativeDeposit> {
+    deserialize(data)
+}
+
+pub fn pending_native_withdrawal(data: &[u8]) -> Result<PendingNativeWithdrawal> {
+    deserialize(data)
+}
+
+pub fn user_native_balance(data: &[u8]) -> Result<UserNativeBalance> {
+    deserialize(data)
+}
+
+pub fn request_record(data: &[u8]) -> Result<RequestRecord> {
+    deserialize(data)
+}
+
+pub fn request_tombstone(data: &[u8]) -> Result<RequestTombstone> {
+    deserialize(data)
+}
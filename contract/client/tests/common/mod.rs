@@ -0,0 +1,1108 @@
+//! LiteSVM harness for the vault program.
+//!
+//! Loads the vault program next to `mock-chain-signatures`, which accepts
+//! `sign_respond` without doing anything, and answers requests with a
+//! `MockResponder` holding a local secp256k1 key registered as the MPC signer.
+//! Both programs are loaded from `target/deploy`, so run `anchor build` first.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::{borsh, Clock, Pubkey};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::{AccountDeserialize, AnchorDeserialize};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use chain_signatures::{AffinePoint, Signature};
+use k256::ecdsa::SigningKey;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use litesvm::types::{FailedTransactionMetadata, TransactionMetadata};
+use litesvm::LiteSVM;
+use solana_core_contracts::error::ErrorCode;
+use solana_core_contracts::instructions::NonFunctionCallResult;
+use solana_core_contracts_client::instructions::{self, args};
+use solana_core_contracts_client::state::{
+    ChainConfigParams, EvmTransactionParams, NonceTracker, PauseFlags, PendingErc20Deposit,
+    PendingErc20Withdrawal, PendingNativeDeposit, PendingNativeWithdrawal, RequestRecord,
+    RequestStatus, TokenConfig, TokenConfigParams, UserErc20Balance, UserNativeBalance,
+    VaultConfig, VaultConfigParams,
+};
+use solana_core_contracts_client::{
+    deposit_request_id, evm, hash_message, pda, withdrawal_request_id, PROGRAM_ID,
+};
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+pub use solana_core_contracts::instructions::erc20_vault::ERROR_PREFIX;
+
+pub type TxResult = Result<TransactionMetadata, FailedTransactionMetadata>;
+
+pub const CHAIN_ID: u64 = 11_155_111;
+pub const ERC20_ADDRESS: [u8; 20] = [0x1c; 20];
+pub const SWEEP_RECIPIENT: [u8; 20] = [0xaa; 20];
+pub const EVM_RECIPIENT: [u8; 20] = [0xbb; 20];
+pub const WITHDRAWAL_ROOT_PATH: &str = "root";
+pub const KEY_VERSION: u32 = 0;
+pub const DEPOSIT_TIMEOUT_SLOTS: u64 = 1_000;
+pub const WITHDRAWAL_REFUND_DELAY_SLOTS: u64 = VaultConfig::MIN_WITHDRAWAL_REFUND_DELAY_SLOTS;
+pub const LARGE_WITHDRAWAL_DELAY_SLOTS: u64 = 100;
+pub const MAX_GAS_LIMIT: u128 = 200_000;
+pub const MAX_FEE_PER_GAS: u128 = 100_000_000_000;
+
+/// Signs responses the way the MPC network does, with a key known to the test
+pub struct MockResponder {
+    key: SigningKey,
+}
+
+impl MockResponder {
+    pub fn new(seed: u8) -> Self {
+        Self {
+            key: SigningKey::from_slice(&[seed; 32]).unwrap(),
+        }
+    }
+
+    /// EVM address the program recovers from this responder's signatures
+    pub fn address(&self) -> [u8; 20] {
+        let point = self.key.verifying_key().to_encoded_point(false);
+        let hash = keccak::hash(&point.as_bytes()[1..]).to_bytes();
+        hash[12..].try_into().unwrap()
+    }
+
+    pub fn respond(&self, request_id: &[u8; 32], serialized_output: &[u8]) -> Signature {
+        self.sign(&hash_message(request_id, serialized_output))
+    }
+
+    /// Signs an unsigned EVM transaction as if this key were the derived address sending it
+    pub fn sign_transaction(&self, rlp_encoded_tx: &[u8]) -> Signature {
+        self.sign(&evm::signing_hash(rlp_encoded_tx))
+    }
+
+    fn sign(&self, message_hash: &[u8; 32]) -> Signature {
+        let (signature, recovery_id) = self.key.sign_prehash_recoverable(message_hash).unwrap();
+
+        Signature {
+            // Only the x coordinate of R is used on-chain
+            big_r: AffinePoint {
+                x: signature.r().to_bytes().into(),
+                y: [0u8; 32],
+            },
+            s: signature.s().to_bytes().into(),
+            recovery_id: recovery_id.to_byte(),
+        }
+    }
+}
+
+pub fn transfer_output(success: bool) -> Vec<u8> {
+    borsh::to_vec(&success).unwrap()
+}
+
+pub fn value_transfer_output() -> Vec<u8> {
+    borsh::to_vec(&NonFunctionCallResult {
+        message: "ok".to_string(),
+    })
+    .unwrap()
+}
+
+pub fn failure_output() -> Vec<u8> {
+    let mut output = ERROR_PREFIX.to_vec();
+    output.extend_from_slice(b"execution reverted");
+    output
+}
+
+/// Asserts that `result` failed in the vault instruction with `error`
+pub fn assert_vault_error<T>(result: Result<T, FailedTransactionMetadata>, error: ErrorCode) {
+    let Err(failed) = result else {
+        panic!("expected {error:?}, transaction succeeded");
+    };
+
+    // Index 0 is the compute budget instruction every harness transaction starts with
+    assert_eq!(
+        failed.err,
+        TransactionError::InstructionError(1, InstructionError::Custom(error.into()))
+    );
+}
+
+pub struct Harness {
+    pub svm: LiteSVM,
+    pub authority: Keypair,
+    pub user: Keypair,
+    pub responder: MockResponder,
+    /// Holds the key of the withdrawal address, signs the withdrawal transactions
+    pub withdrawal_key: MockResponder,
+    nonce: u64,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        let mut harness = Self::deploy();
+        harness.initialize();
+        harness
+    }
+
+    /// Programs loaded with `authority` as the vault's upgrade authority, config not yet
+    /// initialized
+    pub fn deploy() -> Self {
+        let authority = Keypair::new();
+        let user = Keypair::new();
+
+        let mut svm = LiteSVM::new();
+        deploy_upgradeable(
+            &mut svm,
+            PROGRAM_ID,
+            program_path("solana_core_contracts"),
+            authority.pubkey(),
+        );
+        svm.add_program_from_file(
+            mock_chain_signatures::ID,
+            program_path("mock_chain_signatures"),
+        )
+        .expect("mock chain signatures program not built, run `anchor build`");
+
+        svm.airdrop(&authority.pubkey(), 100_000_000_000).unwrap();
+        svm.airdrop(&user.pubkey(), 100_000_000_000).unwrap();
+
+        Self {
+            svm,
+            authority,
+            user,
+            responder: MockResponder::new(7),
+            withdrawal_key: MockResponder::new(11),
+            nonce: 0,
+        }
+    }
+
+    /// Config the vault is initialized with
+    pub fn config_params(&self) -> VaultConfigParams {
+        VaultConfigParams {
+            guardian: self.authority.pubkey(),
+            sweep_recipient: SWEEP_RECIPIENT,
+            withdrawal_root_path: WITHDRAWAL_ROOT_PATH.to_string(),
+            chain_signatures_program: mock_chain_signatures::ID,
+            key_rotation_grace_period: 0,
+            fungible_chain_ids: vec![],
+            deposit_timeout_slots: DEPOSIT_TIMEOUT_SLOTS,
+            withdrawal_refund_delay_slots: WITHDRAWAL_REFUND_DELAY_SLOTS,
+            fee_authority: self.authority.pubkey(),
+            large_withdrawal_delay_slots: LARGE_WITHDRAWAL_DELAY_SLOTS,
+        }
+    }
+
+    /// Registry entry tokens are registered with, free of protocol fees, rate limits and queueing
+    pub fn token_params(&self, symbol: &str) -> TokenConfigParams {
+        TokenConfigParams {
+            enabled: true,
+            decimals: 6,
+            symbol: symbol.to_string(),
+            name: format!("Bridged {symbol}"),
+            uri: String::new(),
+            min_deposit: 1,
+            max_deposit: u128::MAX,
+            min_withdrawal: 1,
+            max_withdrawal: u128::MAX,
+            fee_bps: 0,
+            min_fee: 0,
+            withdrawal_limit: 0,
+            user_withdrawal_limit: 0,
+            rate_limit_window_slots: 0,
+            large_withdrawal_threshold: 0,
+        }
+    }
+
+    pub fn initialize(&mut self) {
+        let authority = self.authority.pubkey();
+
+        self.send_as_authority(instructions::initialize_config(
+            authority,
+            args::InitializeConfig {
+                params: self.config_params(),
+            },
+        ))
+        .expect("initialize_config failed");
+
+        self.send_as_authority(instructions::register_mpc_signer(
+            authority,
+            args::RegisterMpcSigner {
+                key_version: KEY_VERSION,
+                address: self.responder.address(),
+                withdrawal_address: self.withdrawal_key.address(),
+            },
+        ))
+        .expect("register_mpc_signer failed");
+
+        self.register_chain(CHAIN_ID);
+    }
+
+    /// Registers the test tokens, gas limits and a withdrawal nonce tracker on `chain_id`
+    pub fn register_chain(&mut self, chain_id: u64) {
+        let authority = self.authority.pubkey();
+
+        for (erc20_address, symbol) in [
+            (ERC20_ADDRESS, "USDC"),
+            (
+                solana_core_contracts::constants::NATIVE_TOKEN_ADDRESS,
+                "ETH",
+            ),
+        ] {
+            self.send_as_authority(instructions::register_token(
+                authority,
+                args::RegisterToken {
+                    chain_id,
+                    erc20_address,
+                    params: self.token_params(symbol),
+                },
+            ))
+            .expect("register_token failed");
+        }
+
+        self.send_as_authority(instructions::set_chain_config(
+            authority,
+            args::SetChainConfig {
+                chain_id,
+                params: ChainConfigParams {
+                    max_gas_limit: MAX_GAS_LIMIT,
+                    max_fee_per_gas: MAX_FEE_PER_GAS,
+                    max_priority_fee_per_gas: MAX_FEE_PER_GAS,
+                },
+            },
+        ))
+        .expect("set_chain_config failed");
+
+        self.send_as_authority(instructions::sync_nonce_tracker(
+            authority,
+            args::SyncNonceTracker {
+                chain_id,
+                path: WITHDRAWAL_ROOT_PATH.to_string(),
+                key_version: KEY_VERSION,
+                next_nonce: 0,
+            },
+        ))
+        .expect("sync_nonce_tracker failed");
+    }
+
+    /// Sets the next nonce of the withdrawal address derived under `key_version`
+    pub fn sync_nonce_tracker(&mut self, key_version: u32, next_nonce: u64) -> TxResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(instructions::sync_nonce_tracker(
+            authority,
+            args::SyncNonceTracker {
+                chain_id: CHAIN_ID,
+                path: WITHDRAWAL_ROOT_PATH.to_string(),
+                key_version,
+                next_nonce,
+            },
+        ))
+    }
+
+    pub fn send_as_authority(&mut self, ix: Instruction) -> TxResult {
+        send(&mut self.svm, ix, &self.authority)
+    }
+
+    pub fn send_as_user(&mut self, ix: Instruction) -> TxResult {
+        send(&mut self.svm, ix, &self.user)
+    }
+
+    pub fn send_as(&mut self, ix: Instruction, signer: &Keypair) -> TxResult {
+        send(&mut self.svm, ix, signer)
+    }
+
+    /// Simulates `ix` as the user and decodes its Anchor return data
+    pub fn simulate_as_user<T: AnchorDeserialize>(&mut self, ix: Instruction) -> T {
+        let tx = transaction(&self.svm, ix, &self.user);
+        let info = self
+            .svm
+            .simulate_transaction(tx)
+            .expect("simulation failed");
+        T::try_from_slice(&info.meta.return_data.data).unwrap()
+    }
+
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Option<T> {
+        let account = self.svm.get_account(address)?;
+        if account.data.is_empty() {
+            return None;
+        }
+        Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+    }
+
+    pub fn warp_slots(&mut self, slots: u64) {
+        let slot = self.slot();
+        self.svm.warp_to_slot(slot + slots);
+    }
+
+    /// Moves the clock's unix timestamp, which MPC signer validity is measured in
+    pub fn warp_seconds(&mut self, seconds: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += seconds;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn erc20_balance(&self) -> u128 {
+        self.erc20_balance_of(&self.user.pubkey())
+    }
+
+    /// Protocol fees accrued on the test ERC20 and not yet collected
+    pub fn erc20_fee_balance(&self) -> u128 {
+        let address = pda::fee_balance(CHAIN_ID, &ERC20_ADDRESS).0;
+        self.account::<UserErc20Balance>(&address)
+            .map_or(0, |balance| balance.amount)
+    }
+
+    /// Collects accrued fees of the test ERC20 into `signer`'s balance
+    pub fn collect_fees(&mut self, amount: u128, signer: &Keypair) -> TxResult {
+        self.send_as(
+            instructions::collect_fees(
+                signer.pubkey(),
+                args::CollectFees {
+                    chain_id: CHAIN_ID,
+                    erc20_address: ERC20_ADDRESS,
+                    amount,
+                },
+            ),
+            signer,
+        )
+    }
+
+    /// Protocol fee the user pays on `amount` of the test ERC20, zero when the program
+    /// would reject the amount anyway
+    pub fn erc20_fee(&self, amount: u128) -> u128 {
+        let token: TokenConfig = self
+            .account(&pda::token_config(CHAIN_ID, &ERC20_ADDRESS).0)
+            .expect("token config missing");
+        token.fee_for(amount).unwrap_or(0)
+    }
+
+    pub fn vault_config(&self) -> VaultConfig {
+        self.account(&pda::vault_config().0)
+            .expect("vault config missing")
+    }
+
+    /// `signer` is the config authority or the guardian
+    pub fn set_pause_flags(&mut self, flags: PauseFlags, signer: &Keypair) -> TxResult {
+        self.send_as(
+            instructions::set_pause_flags(signer.pubkey(), args::SetPauseFlags { flags }),
+            signer,
+        )
+    }
+
+    /// Registers `responder` for `key_version`, withdrawals under it are sent from
+    /// `withdrawal_key`'s address
+    pub fn register_mpc_signer(
+        &mut self,
+        key_version: u32,
+        responder: &MockResponder,
+        withdrawal_key: &MockResponder,
+    ) -> TxResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(instructions::register_mpc_signer(
+            authority,
+            args::RegisterMpcSigner {
+                key_version,
+                address: responder.address(),
+                withdrawal_address: withdrawal_key.address(),
+            },
+        ))
+    }
+
+    pub fn revoke_mpc_signer(&mut self, key_version: u32) -> TxResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(instructions::revoke_mpc_signer(
+            authority,
+            args::RevokeMpcSigner { key_version },
+        ))
+    }
+
+    pub fn rotate_mpc_key(&mut self, new_key_version: u32) -> TxResult {
+        let authority = self.authority.pubkey();
+        let current_key_version = self.vault_config().current_key_version;
+        self.send_as_authority(instructions::rotate_mpc_key(
+            authority,
+            current_key_version,
+            args::RotateMpcKey { new_key_version },
+        ))
+    }
+
+    pub fn erc20_balance_of(&self, user: &Pubkey) -> u128 {
+        let address = pda::user_erc20_balance(user, CHAIN_ID, &ERC20_ADDRESS).0;
+        self.account::<UserErc20Balance>(&address)
+            .map_or(0, |balance| balance.amount)
+    }
+
+    pub fn native_balance(&self) -> u128 {
+        let address = pda::user_native_balance(&self.user.pubkey(), CHAIN_ID).0;
+        self.account::<UserNativeBalance>(&address)
+            .map_or(0, |balance| balance.amount)
+    }
+
+    pub fn request_status(&self, request_id: &[u8; 32]) -> RequestStatus {
+        self.account::<RequestRecord>(&pda::request_record(request_id).0)
+            .expect("request record missing")
+            .status
+    }
+
+    /// Compresses the record with `signer`, the requester or the vault authority
+    pub fn compress_request_record(&mut self, request_id: [u8; 32], signer: &Keypair) -> TxResult {
+        let record: RequestRecord = self
+            .account(&pda::request_record(&request_id).0)
+            .expect("request record missing");
+
+        self.send_as(
+            instructions::compress_request_record(
+                signer.pubkey(),
+                &record,
+                args::CompressRequestRecord { request_id },
+            ),
+            signer,
+        )
+    }
+
+    pub fn next_tx_params(&mut self, value: u128) -> EvmTransactionParams {
+        self.nonce += 1;
+        self.tx_params(value, self.nonce)
+    }
+
+    pub fn tx_params(&self, value: u128, nonce: u64) -> EvmTransactionParams {
+        EvmTransactionParams {
+            value,
+            gas_limit: 100_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            nonce,
+            chain_id: CHAIN_ID,
+        }
+    }
+
+    /// Transaction params for the next withdrawal, at the nonce the program expects
+    pub fn withdrawal_tx_params(&self, value: u128) -> EvmTransactionParams {
+        let key_version = self.vault_config().current_key_version;
+        let tracker: NonceTracker = self
+            .account(&pda::nonce_tracker(CHAIN_ID, WITHDRAWAL_ROOT_PATH, key_version).0)
+            .expect("nonce tracker missing");
+
+        EvmTransactionParams {
+            nonce: tracker.next_nonce,
+            ..self.tx_params(value, 0)
+        }
+    }
+
+    pub fn deposit_erc20(&mut self, amount: u128) -> [u8; 32] {
+        self.deposit_erc20_as(amount, false)
+    }
+
+    /// Deposit claimed as wrapped tokens instead of a balance
+    pub fn deposit_wrapped_erc20(&mut self, amount: u128) -> [u8; 32] {
+        self.deposit_erc20_as(amount, true)
+    }
+
+    fn deposit_erc20_as(&mut self, amount: u128, wrapped: bool) -> [u8; 32] {
+        self.deposit_erc20_with(ERC20_ADDRESS, amount, wrapped)
+            .expect("deposit_erc20 failed")
+    }
+
+    pub fn deposit_erc20_with(
+        &mut self,
+        erc20_address: [u8; 20],
+        amount: u128,
+        wrapped: bool,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let user = self.user.pubkey();
+        let tx_params = self.next_tx_params(0);
+        let rlp = evm::build_erc20_transfer(erc20_address, SWEEP_RECIPIENT, amount, &tx_params);
+        let request_id = deposit_request_id(&user, &rlp, self.vault_config().current_key_version);
+
+        self.send_as_user(instructions::deposit_erc20(
+            user,
+            Some(user),
+            mock_chain_signatures::ID,
+            wrapped,
+            args::DepositErc20 {
+                request_id,
+                requester: user,
+                erc20_address,
+                amount,
+                tx_params,
+                relayer_tip: 0,
+            },
+        ))?;
+
+        Ok(request_id)
+    }
+
+    pub fn claim_erc20(&mut self, request_id: [u8; 32], output: Vec<u8>) -> TxResult {
+        let signature = self.responder.respond(&request_id, &output);
+        self.claim_erc20_with(request_id, output, signature)
+    }
+
+    pub fn claim_erc20_with(
+        &mut self,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> TxResult {
+        let pending: PendingErc20Deposit = self
+            .account(&pda::pending_erc20_deposit(&request_id).0)
+            .expect("pending deposit missing");
+
+        self.send_as_authority(instructions::claim_erc20(
+            self.authority.pubkey(),
+            &pending,
+            args::ClaimErc20 {
+                request_id,
+                serialized_output,
+                signature,
+            },
+        ))
+    }
+
+    pub fn cancel_expired_deposit(&mut self, request_id: [u8; 32]) -> TxResult {
+        let pending: PendingErc20Deposit = self
+            .account(&pda::pending_erc20_deposit(&request_id).0)
+            .expect("pending deposit missing");
+
+        self.send_as_user(instructions::cancel_expired_deposit(
+            &pending,
+            args::CancelExpiredDeposit { request_id },
+        ))
+    }
+
+    pub fn withdraw_erc20(&mut self, amount: u128) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let tx_params = self.withdrawal_tx_params(0);
+        self.withdraw_erc20_with(amount, tx_params)
+    }
+
+    pub fn withdraw_erc20_with(
+        &mut self,
+        amount: u128,
+        tx_params: EvmTransactionParams,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let user = self.user.pubkey();
+        let (request_id, ix) = self.withdraw_erc20_ix(user, amount, tx_params, false);
+        self.send_as_user(ix)?;
+
+        Ok(request_id)
+    }
+
+    /// Withdraws by burning the user's wrapped tokens
+    pub fn withdraw_wrapped_erc20(
+        &mut self,
+        amount: u128,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let tx_params = self.withdrawal_tx_params(0);
+        let user = self.user.pubkey();
+        let (request_id, ix) = self.withdraw_erc20_ix(user, amount, tx_params, true);
+        self.send_as_user(ix)?;
+
+        Ok(request_id)
+    }
+
+    /// Withdraws the user's balance with `authority` signing as their delegate
+    pub fn delegated_withdraw_erc20(
+        &mut self,
+        amount: u128,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let tx_params = self.withdrawal_tx_params(0);
+        let authority = self.authority.pubkey();
+        let (request_id, ix) = self.withdraw_erc20_ix(authority, amount, tx_params, false);
+        self.send_as_authority(ix)?;
+
+        Ok(request_id)
+    }
+
+    fn withdraw_erc20_ix(
+        &self,
+        signer: Pubkey,
+        amount: u128,
+        tx_params: EvmTransactionParams,
+        wrapped: bool,
+    ) -> ([u8; 32], Instruction) {
+        // The transfer sends the amount net of the protocol fee
+        let net_amount = amount - self.erc20_fee(amount);
+        let rlp = evm::build_erc20_transfer(ERC20_ADDRESS, EVM_RECIPIENT, net_amount, &tx_params);
+        let key_version = self.vault_config().current_key_version;
+        let request_id = withdrawal_request_id(&rlp, key_version, WITHDRAWAL_ROOT_PATH);
+
+        let ix = instructions::withdraw_erc20(
+            signer,
+            self.user.pubkey(),
+            Some(signer),
+            mock_chain_signatures::ID,
+            WITHDRAWAL_ROOT_PATH,
+            key_version,
+            wrapped,
+            args::WithdrawErc20 {
+                request_id,
+                erc20_address: ERC20_ADDRESS,
+                amount,
+                recipient_address: EVM_RECIPIENT,
+                tx_params,
+                source_chain_id: CHAIN_ID,
+                relayer_tip: 0,
+            },
+        );
+
+        (request_id, ix)
+    }
+
+    pub fn complete_withdraw_erc20(&mut self, request_id: [u8; 32], output: Vec<u8>) -> TxResult {
+        self.respond_to_withdrawal(request_id, request_id, output)
+    }
+
+    /// Delivers the response to `request_id`, a fee bump of the withdrawal `original_request_id`
+    pub fn respond_to_withdrawal(
+        &mut self,
+        original_request_id: [u8; 32],
+        request_id: [u8; 32],
+        output: Vec<u8>,
+    ) -> TxResult {
+        let pending: PendingErc20Withdrawal = self
+            .account(&pda::pending_erc20_withdrawal(&original_request_id).0)
+            .expect("pending withdrawal missing");
+        let signature = self.responder.respond(&request_id, &output);
+
+        self.send_as_authority(instructions::complete_withdraw_erc20(
+            self.authority.pubkey(),
+            &pending,
+            args::CompleteWithdrawErc20 {
+                request_id,
+                serialized_output: output,
+                signature,
+            },
+        ))
+    }
+
+    /// Re-requests the withdrawal with fees raised by 20%, returns the replacement request id
+    pub fn bump_withdrawal_fee(
+        &mut self,
+        request_id: [u8; 32],
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let pending: PendingErc20Withdrawal = self
+            .account(&pda::pending_erc20_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+
+        let mut tx_params = pending.tx_params.clone();
+        tx_params.max_fee_per_gas = tx_params.max_fee_per_gas * 12 / 10;
+        tx_params.max_priority_fee_per_gas = tx_params.max_priority_fee_per_gas * 12 / 10;
+
+        let rlp = evm::build_erc20_transfer(
+            pending.erc20_address,
+            pending.recipient_address,
+            pending.net_amount().unwrap(),
+            &tx_params,
+        );
+        let replacement_request_id =
+            withdrawal_request_id(&rlp, pending.key_version, WITHDRAWAL_ROOT_PATH);
+
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::bump_withdrawal_fee(
+            user,
+            Some(user),
+            mock_chain_signatures::ID,
+            args::BumpWithdrawalFee {
+                request_id,
+                replacement_request_id,
+                tx_params,
+            },
+        ))?;
+
+        Ok(replacement_request_id)
+    }
+
+    /// Records the signature of `signed_request_id`, the withdrawal `request_id` or one of its
+    /// fee bumps
+    pub fn record_signed_erc20_withdrawal(
+        &mut self,
+        request_id: [u8; 32],
+        signed_request_id: [u8; 32],
+        signature: Signature,
+    ) -> TxResult {
+        self.send_as_user(instructions::record_signed_erc20_withdrawal(
+            args::RecordSignedErc20Withdrawal {
+                request_id,
+                signed_request_id,
+                signature,
+            },
+        ))
+    }
+
+    /// Sends a queued withdrawal for signing at the next nonce, returns the signed request id
+    pub fn execute_queued_withdrawal(
+        &mut self,
+        request_id: [u8; 32],
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let user = self.user.insecure_clone();
+        self.execute_queued_withdrawal_as(request_id, &user)
+    }
+
+    /// Executes a queued withdrawal with `payer` signing, anyone can once the delay is over
+    pub fn execute_queued_withdrawal_as(
+        &mut self,
+        request_id: [u8; 32],
+        payer: &Keypair,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let pending: PendingErc20Withdrawal = self
+            .account(&pda::pending_erc20_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+
+        let tx_params = self.withdrawal_tx_params(0);
+        let rlp = evm::build_erc20_transfer(
+            pending.erc20_address,
+            pending.recipient_address,
+            pending.net_amount().unwrap(),
+            &tx_params,
+        );
+        let key_version = self.vault_config().current_key_version;
+        let signed_request_id = withdrawal_request_id(&rlp, key_version, WITHDRAWAL_ROOT_PATH);
+
+        self.send_as(
+            instructions::execute_queued_withdrawal(
+                payer.pubkey(),
+                Some(payer.pubkey()),
+                mock_chain_signatures::ID,
+                WITHDRAWAL_ROOT_PATH,
+                key_version,
+                args::ExecuteQueuedWithdrawal {
+                    request_id,
+                    signed_request_id,
+                    tx_params,
+                },
+            ),
+            payer,
+        )?;
+
+        Ok(signed_request_id)
+    }
+
+    /// Vetoes a queued withdrawal as the guardian, which is the authority in this harness
+    pub fn cancel_queued_withdrawal(&mut self, request_id: [u8; 32]) -> TxResult {
+        let pending: PendingErc20Withdrawal = self
+            .account(&pda::pending_erc20_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+
+        self.send_as_authority(instructions::cancel_queued_withdrawal(
+            self.authority.pubkey(),
+            &pending,
+            args::CancelQueuedWithdrawal { request_id },
+        ))
+    }
+
+    pub fn transfer_erc20_balance(&mut self, recipient: Pubkey, amount: u128) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::transfer_erc20_balance(
+            user,
+            user,
+            args::TransferErc20Balance {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                recipient,
+                amount,
+            },
+        ))
+    }
+
+    /// Sends the user's balance with `authority` signing as their delegate
+    pub fn delegated_transfer_erc20_balance(
+        &mut self,
+        recipient: Pubkey,
+        amount: u128,
+    ) -> TxResult {
+        let authority = self.authority.pubkey();
+        let user = self.user.pubkey();
+        self.send_as_authority(instructions::transfer_erc20_balance(
+            authority,
+            user,
+            args::TransferErc20Balance {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                recipient,
+                amount,
+            },
+        ))
+    }
+
+    pub fn approve_withdrawal(
+        &mut self,
+        delegate: Pubkey,
+        amount: u128,
+        expires_at_slot: u64,
+    ) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::approve_withdrawal(
+            user,
+            args::ApproveWithdrawal {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                delegate,
+                amount,
+                expires_at_slot,
+            },
+        ))
+    }
+
+    pub fn revoke_withdrawal_allowance(&mut self, delegate: Pubkey) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::revoke_withdrawal_allowance(
+            user,
+            args::RevokeWithdrawalAllowance {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                delegate,
+            },
+        ))
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.svm.get_sysvar::<Clock>().slot
+    }
+
+    pub fn create_wrapped_mint(&mut self) -> TxResult {
+        let authority = self.authority.pubkey();
+        self.send_as_authority(instructions::create_wrapped_mint(
+            authority,
+            args::CreateWrappedMint {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+            },
+        ))
+    }
+
+    pub fn create_wrapped_token_account(&mut self) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::create_wrapped_token_account(
+            user,
+            user,
+            CHAIN_ID,
+            &ERC20_ADDRESS,
+        ))
+    }
+
+    pub fn wrap_erc20_balance(&mut self, amount: u128) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::wrap_erc20_balance(
+            user,
+            args::WrapErc20Balance {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                amount,
+            },
+        ))
+    }
+
+    pub fn unwrap_erc20_balance(&mut self, amount: u128) -> TxResult {
+        let user = self.user.pubkey();
+        self.send_as_user(instructions::unwrap_erc20_balance(
+            user,
+            args::UnwrapErc20Balance {
+                chain_id: CHAIN_ID,
+                erc20_address: ERC20_ADDRESS,
+                amount,
+            },
+        ))
+    }
+
+    pub fn wrapped_token_balance(&self) -> u64 {
+        let address =
+            instructions::wrapped_token_account(&self.user.pubkey(), CHAIN_ID, &ERC20_ADDRESS);
+        self.account::<TokenAccount>(&address)
+            .map_or(0, |account| account.amount)
+    }
+
+    pub fn wrapped_mint_decimals(&self) -> u8 {
+        self.account::<Mint>(&pda::wrapped_mint(CHAIN_ID, &ERC20_ADDRESS).0)
+            .expect("wrapped mint missing")
+            .decimals
+    }
+
+    pub fn refund_expired_withdrawal(&mut self, request_id: [u8; 32]) -> TxResult {
+        let pending: PendingErc20Withdrawal = self
+            .account(&pda::pending_erc20_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+
+        self.send_as_user(instructions::refund_expired_withdrawal(
+            &pending,
+            args::RefundExpiredWithdrawal { request_id },
+        ))
+    }
+
+    pub fn deposit_native(&mut self, amount: u128) -> [u8; 32] {
+        let tx_params = self.next_tx_params(amount);
+        self.deposit_native_with(amount, tx_params)
+            .expect("deposit_native failed")
+    }
+
+    pub fn deposit_native_with(
+        &mut self,
+        amount: u128,
+        tx_params: EvmTransactionParams,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let user = self.user.pubkey();
+        let rlp = evm::build_value_transfer(SWEEP_RECIPIENT, &tx_params);
+        let request_id = deposit_request_id(&user, &rlp, self.vault_config().current_key_version);
+
+        self.send_as_user(instructions::deposit_native(
+            user,
+            Some(user),
+            mock_chain_signatures::ID,
+            args::DepositNative {
+                request_id,
+                requester: user,
+                amount,
+                tx_params,
+                relayer_tip: 0,
+            },
+        ))?;
+
+        Ok(request_id)
+    }
+
+    pub fn withdraw_native_with(
+        &mut self,
+        amount: u128,
+        tx_params: EvmTransactionParams,
+    ) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let user = self.user.pubkey();
+        let rlp = evm::build_value_transfer(EVM_RECIPIENT, &tx_params);
+        let key_version = self.vault_config().current_key_version;
+        let request_id = withdrawal_request_id(&rlp, key_version, WITHDRAWAL_ROOT_PATH);
+
+        self.send_as_user(instructions::withdraw_native(
+            user,
+            Some(user),
+            mock_chain_signatures::ID,
+            WITHDRAWAL_ROOT_PATH,
+            key_version,
+            args::WithdrawNative {
+                request_id,
+                amount,
+                recipient_address: EVM_RECIPIENT,
+                tx_params,
+                relayer_tip: 0,
+            },
+        ))?;
+
+        Ok(request_id)
+    }
+
+    pub fn withdraw_native(&mut self, amount: u128) -> Result<[u8; 32], FailedTransactionMetadata> {
+        let tx_params = self.withdrawal_tx_params(amount);
+        self.withdraw_native_with(amount, tx_params)
+    }
+
+    pub fn complete_withdraw_native(&mut self, request_id: [u8; 32], output: Vec<u8>) -> TxResult {
+        let pending: PendingNativeWithdrawal = self
+            .account(&pda::pending_native_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+        let signature = self.responder.respond(&request_id, &output);
+
+        self.send_as_authority(instructions::complete_withdraw_native(
+            self.authority.pubkey(),
+            &pending,
+            args::CompleteWithdrawNative {
+                request_id,
+                serialized_output: output,
+                signature,
+            },
+        ))
+    }
+
+    pub fn refund_expired_native_withdrawal(&mut self, request_id: [u8; 32]) -> TxResult {
+        let pending: PendingNativeWithdrawal = self
+            .account(&pda::pending_native_withdrawal(&request_id).0)
+            .expect("pending withdrawal missing");
+
+        self.send_as_user(instructions::refund_expired_native_withdrawal(
+            &pending,
+            args::RefundExpiredNativeWithdrawal { request_id },
+        ))
+    }
+
+    pub fn claim_native(&mut self, request_id: [u8; 32], output: Vec<u8>) -> TxResult {
+        let pending: PendingNativeDeposit = self
+            .account(&pda::pending_native_deposit(&request_id).0)
+            .expect("pending deposit missing");
+        let signature = self.responder.respond(&request_id, &output);
+
+        self.send_as_authority(instructions::claim_native(
+            self.authority.pubkey(),
+            &pending,
+            args::ClaimNative {
+                request_id,
+                serialized_output: output,
+                signature,
+            },
+        ))
+    }
+}
+
+// `initialize_config` checks the upgrade authority, so the vault is loaded the way
+// `solana program deploy` leaves it: a program account pointing at its program data
+fn deploy_upgradeable(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    path: PathBuf,
+    upgrade_authority: Pubkey,
+) {
+    let elf = std::fs::read(path).expect("vault program not built, run `anchor build`");
+    let program_data_address = pda::program_data().0;
+
+    let mut program_data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(upgrade_authority),
+    })
+    .unwrap();
+    program_data.resize(UpgradeableLoaderState::size_of_programdata_metadata(), 0);
+    program_data.extend_from_slice(&elf);
+    svm.set_account(
+        program_data_address,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(program_data.len()),
+            data: program_data,
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let program = bincode::serialize(&UpgradeableLoaderState::Program {
+        programdata_address: program_data_address,
+    })
+    .unwrap();
+    svm.set_account(
+        program_id,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(program.len()),
+            data: program,
+            owner: bpf_loader_upgradeable::ID,
+            executable: true,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+fn program_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/deploy")
+        .join(format!("{name}.so"))
+}
+
+fn transaction(svm: &LiteSVM, ix: Instruction, payer: &Keypair) -> Transaction {
+    Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            ix,
+        ],
+        Some(&payer.pubkey()),
+        &[payer],
+        svm.latest_blockhash(),
+    )
+}
+
+fn send(svm: &mut LiteSVM, ix: Instruction, payer: &Keypair) -> TxResult {
+    let tx = transaction(svm, ix, payer);
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
@@ -0,0 +1,1228 @@
+//! End-to-end vault flows against the mock chain signatures program.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use solana_core_contracts::error::ErrorCode;
+use solana_core_contracts_client::instructions::{self, args};
+use solana_core_contracts_client::pda;
+use solana_core_contracts_client::state::{
+    EvmTransactionParams, NonceTracker, PauseFlags, PendingErc20Withdrawal, RequestStatus,
+    RequestTombstone, SignRequestPreview, TokenConfigParams, VaultConfigParams,
+};
+use solana_core_contracts_client::{deposit_request_id, evm, withdrawal_request_id};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[test]
+fn erc20_deposit_is_credited_on_claim() {
+    let mut h = Harness::new();
+
+    let request_id = h.deposit_erc20(1_000);
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Pending);
+
+    h.claim_erc20(request_id, transfer_output(true)).unwrap();
+
+    assert_eq!(h.erc20_balance(), 1_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn failed_erc20_deposit_is_settled_without_credit() {
+    let mut h = Harness::new();
+
+    let request_id = h.deposit_erc20(1_000);
+    h.claim_erc20(request_id, failure_output()).unwrap();
+
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Failed);
+}
+
+#[test]
+fn claim_signed_by_unknown_key_is_rejected() {
+    let mut h = Harness::new();
+
+    let request_id = h.deposit_erc20(1_000);
+    let output = transfer_output(true);
+    let forged = MockResponder::new(9).respond(&request_id, &output);
+
+    assert!(h.claim_erc20_with(request_id, output, forged).is_err());
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Pending);
+}
+
+#[test]
+fn erc20_withdrawal_debits_balance_on_completion() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+
+    h.complete_withdraw_erc20(request_id, transfer_output(true))
+        .unwrap();
+
+    assert_eq!(h.erc20_balance(), 600);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn failed_erc20_withdrawal_is_refunded() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    h.complete_withdraw_erc20(request_id, failure_output())
+        .unwrap();
+
+    assert_eq!(h.erc20_balance(), 1_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Refunded);
+}
+
+#[test]
+fn withdrawal_above_balance_is_rejected() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    assert!(h.withdraw_erc20(1_001).is_err());
+    assert_eq!(h.erc20_balance(), 1_000);
+}
+
+#[test]
+fn unanswered_deposit_can_be_cancelled_after_timeout() {
+    let mut h = Harness::new();
+
+    let request_id = h.deposit_erc20(1_000);
+    assert!(h.cancel_expired_deposit(request_id).is_err());
+
+    h.warp_slots(DEPOSIT_TIMEOUT_SLOTS);
+    h.cancel_expired_deposit(request_id).unwrap();
+
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Expired);
+}
+
+#[test]
+fn unanswered_withdrawal_is_refunded_after_delay() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    assert!(h.refund_expired_withdrawal(request_id).is_err());
+
+    h.warp_slots(WITHDRAWAL_REFUND_DELAY_SLOTS);
+    h.refund_expired_withdrawal(request_id).unwrap();
+
+    assert_eq!(h.erc20_balance(), 1_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Expired);
+}
+
+#[test]
+fn native_deposit_is_credited_on_claim() {
+    let mut h = Harness::new();
+
+    let request_id = h.deposit_native(5_000);
+    h.claim_native(request_id, value_transfer_output()).unwrap();
+
+    assert_eq!(h.native_balance(), 5_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn native_withdrawal_debits_balance_on_completion() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_native(5_000);
+    h.claim_native(deposit_id, value_transfer_output()).unwrap();
+
+    let request_id = h.withdraw_native(2_000).unwrap();
+    assert_eq!(h.native_balance(), 3_000);
+
+    h.complete_withdraw_native(request_id, value_transfer_output())
+        .unwrap();
+
+    assert_eq!(h.native_balance(), 3_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn failed_native_withdrawal_is_refunded() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_native(5_000);
+    h.claim_native(deposit_id, value_transfer_output()).unwrap();
+
+    let request_id = h.withdraw_native(2_000).unwrap();
+    h.complete_withdraw_native(request_id, failure_output())
+        .unwrap();
+
+    assert_eq!(h.native_balance(), 5_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Refunded);
+}
+
+#[test]
+fn expired_native_withdrawal_is_refunded_after_the_delay() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_native(5_000);
+    h.claim_native(deposit_id, value_transfer_output()).unwrap();
+
+    let request_id = h.withdraw_native(2_000).unwrap();
+    assert_vault_error(
+        h.refund_expired_native_withdrawal(request_id),
+        ErrorCode::RequestNotExpired,
+    );
+
+    h.warp_slots(WITHDRAWAL_REFUND_DELAY_SLOTS);
+    h.refund_expired_native_withdrawal(request_id).unwrap();
+
+    assert_eq!(h.native_balance(), 5_000);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Expired);
+}
+
+#[test]
+fn deposit_preview_matches_client_request_id() {
+    let mut h = Harness::new();
+    let user = h.user.pubkey();
+    let tx_params = h.next_tx_params(0);
+
+    let preview: SignRequestPreview = h.simulate_as_user(instructions::preview_deposit_erc20(
+        args::PreviewDepositErc20 {
+            requester: user,
+            erc20_address: ERC20_ADDRESS,
+            amount: 1_000,
+            tx_params: tx_params.clone(),
+        },
+    ));
+
+    let rlp = evm::build_erc20_transfer(ERC20_ADDRESS, SWEEP_RECIPIENT, 1_000, &tx_params);
+    assert_eq!(preview.rlp_encoded_tx, rlp);
+    assert_eq!(
+        preview.request_id,
+        deposit_request_id(&user, &rlp, KEY_VERSION)
+    );
+    assert_eq!(preview.path, user.to_string());
+}
+
+#[test]
+fn signed_withdrawal_is_recorded_once() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    // Withdrawals are sent from the key's withdrawal address, the harness holds its key
+    let request_id = h.withdraw_erc20(400).unwrap();
+    let pending_address = pda::pending_erc20_withdrawal(&request_id).0;
+    let pending: PendingErc20Withdrawal = h.account(&pending_address).unwrap();
+    assert_eq!(pending.evm_tx_hash, [0u8; 32]);
+
+    let forged = MockResponder::new(9).sign_transaction(&pending.rlp_encoded_tx);
+    assert!(h
+        .record_signed_erc20_withdrawal(request_id, request_id, forged)
+        .is_err());
+
+    let signature = h.withdrawal_key.sign_transaction(&pending.rlp_encoded_tx);
+    let signed = evm::assemble_signed_transaction(&pending.rlp_encoded_tx, &signature).unwrap();
+    h.record_signed_erc20_withdrawal(request_id, request_id, signature)
+        .unwrap();
+
+    let pending: PendingErc20Withdrawal = h.account(&pending_address).unwrap();
+    assert_eq!(signed.raw_transaction[0], 0x02);
+    assert_eq!(pending.evm_tx_hash, signed.tx_hash);
+
+    // Signatures are deterministic, this is the same one again
+    let signature = h.withdrawal_key.sign_transaction(&pending.rlp_encoded_tx);
+    assert!(h
+        .record_signed_erc20_withdrawal(request_id, request_id, signature)
+        .is_err());
+}
+
+#[test]
+fn replaced_withdrawal_signature_can_still_be_recorded() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    let pending_address = pda::pending_erc20_withdrawal(&request_id).0;
+    let original: PendingErc20Withdrawal = h.account(&pending_address).unwrap();
+    let replacement_id = h.bump_withdrawal_fee(request_id).unwrap();
+
+    // The expected signer was pinned when the withdrawal was created, a rotation to a key with
+    // another withdrawal address does not change it
+    let next_key = MockResponder::new(12);
+    h.register_mpc_signer(KEY_VERSION + 1, &next_key, &next_key)
+        .unwrap();
+    h.rotate_mpc_key(KEY_VERSION + 1).unwrap();
+
+    // The original can still land after the bump
+    let signature = h.withdrawal_key.sign_transaction(&original.rlp_encoded_tx);
+    let signed = evm::assemble_signed_transaction(&original.rlp_encoded_tx, &signature).unwrap();
+    h.record_signed_erc20_withdrawal(request_id, request_id, signature)
+        .unwrap();
+    let pending: PendingErc20Withdrawal = h.account(&pending_address).unwrap();
+    assert_eq!(pending.evm_tx_hash, signed.tx_hash);
+
+    let signature = h.withdrawal_key.sign_transaction(&original.rlp_encoded_tx);
+    assert!(h
+        .record_signed_erc20_withdrawal(request_id, request_id, signature)
+        .is_err());
+
+    let signature = h.withdrawal_key.sign_transaction(&pending.rlp_encoded_tx);
+    let signed = evm::assemble_signed_transaction(&pending.rlp_encoded_tx, &signature).unwrap();
+    h.record_signed_erc20_withdrawal(request_id, replacement_id, signature)
+        .unwrap();
+    let pending: PendingErc20Withdrawal = h.account(&pending_address).unwrap();
+    assert_eq!(pending.evm_tx_hash, signed.tx_hash);
+}
+
+#[test]
+fn fee_bumped_withdrawal_settles_once() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    let replacement_id = h.bump_withdrawal_fee(request_id).unwrap();
+
+    // The original was replaced, its failure must not refund while the bump is in flight
+    h.complete_withdraw_erc20(request_id, failure_output())
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Pending);
+
+    h.respond_to_withdrawal(request_id, replacement_id, transfer_output(true))
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+
+    let pending_address = pda::pending_erc20_withdrawal(&request_id).0;
+    assert!(h
+        .account::<PendingErc20Withdrawal>(&pending_address)
+        .is_none());
+}
+
+#[test]
+fn fee_bump_must_raise_fees_on_the_same_nonce() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    let pending: PendingErc20Withdrawal = h
+        .account(&pda::pending_erc20_withdrawal(&request_id).0)
+        .unwrap();
+
+    let user = h.user.pubkey();
+    let mut tx_params = pending.tx_params.clone();
+    tx_params.max_fee_per_gas += 1;
+    tx_params.max_priority_fee_per_gas += 1;
+    let rlp = evm::build_erc20_transfer(ERC20_ADDRESS, EVM_RECIPIENT, 400, &tx_params);
+
+    let result = h.send_as_user(instructions::bump_withdrawal_fee(
+        user,
+        Some(user),
+        mock_chain_signatures::ID,
+        args::BumpWithdrawalFee {
+            request_id,
+            replacement_request_id: withdrawal_request_id(&rlp, KEY_VERSION, WITHDRAWAL_ROOT_PATH),
+            tx_params,
+        },
+    ));
+    assert!(result.is_err());
+}
+
+#[test]
+fn withdrawal_nonces_are_assigned_in_order() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    h.withdraw_erc20(100).unwrap();
+
+    // Reusing the nonce the first withdrawal took is rejected
+    let user = h.user.pubkey();
+    let mut tx_params = h.withdrawal_tx_params(0);
+    tx_params.nonce -= 1;
+    let rlp = evm::build_erc20_transfer(ERC20_ADDRESS, EVM_RECIPIENT, 100, &tx_params);
+    let result = h.send_as_user(instructions::withdraw_erc20(
+        user,
+        user,
+        Some(user),
+        mock_chain_signatures::ID,
+        WITHDRAWAL_ROOT_PATH,
+        KEY_VERSION,
+        false,
+        args::WithdrawErc20 {
+            request_id: withdrawal_request_id(&rlp, KEY_VERSION, WITHDRAWAL_ROOT_PATH),
+            erc20_address: ERC20_ADDRESS,
+            amount: 100,
+            recipient_address: EVM_RECIPIENT,
+            tx_params,
+            source_chain_id: CHAIN_ID,
+            relayer_tip: 0,
+        },
+    ));
+    assert!(result.is_err());
+    assert_eq!(h.erc20_balance(), 900);
+
+    // After a resync the next withdrawal continues from the synced nonce
+    h.sync_nonce_tracker(KEY_VERSION, 5).unwrap();
+    assert_eq!(h.withdrawal_tx_params(0).nonce, 5);
+
+    h.withdraw_erc20(100).unwrap();
+    assert_eq!(h.withdrawal_tx_params(0).nonce, 6);
+}
+
+#[test]
+fn rotated_key_tracks_its_own_withdrawal_nonces() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    h.withdraw_erc20(100).unwrap();
+    h.withdraw_erc20(100).unwrap();
+
+    // The new key derives a fresh withdrawal address, it needs its own tracker
+    let next_key = MockResponder::new(12);
+    h.register_mpc_signer(KEY_VERSION + 1, &next_key, &next_key)
+        .unwrap();
+    h.rotate_mpc_key(KEY_VERSION + 1).unwrap();
+    let tx_params = h.tx_params(0, 2);
+    assert!(h.withdraw_erc20_with(100, tx_params).is_err());
+
+    h.sync_nonce_tracker(KEY_VERSION + 1, 0).unwrap();
+    assert_eq!(h.withdrawal_tx_params(0).nonce, 0);
+    h.withdraw_erc20(100).unwrap();
+    assert_eq!(h.withdrawal_tx_params(0).nonce, 1);
+
+    // The previous key's address keeps its own count
+    let tracker: NonceTracker = h
+        .account(&pda::nonce_tracker(CHAIN_ID, WITHDRAWAL_ROOT_PATH, KEY_VERSION).0)
+        .unwrap();
+    assert_eq!(tracker.next_nonce, 2);
+    assert_eq!(h.erc20_balance(), 700);
+}
+
+#[test]
+fn withdrawal_outside_gas_limits_is_rejected() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let mut tx_params = h.withdrawal_tx_params(0);
+    tx_params.gas_limit = MAX_GAS_LIMIT + 1;
+    assert!(h.withdraw_erc20_with(400, tx_params).is_err());
+
+    let mut tx_params = h.withdrawal_tx_params(0);
+    tx_params.max_fee_per_gas = MAX_FEE_PER_GAS + 1;
+    assert!(h.withdraw_erc20_with(400, tx_params).is_err());
+
+    // ERC20 transfers never carry value
+    let tx_params = h.withdrawal_tx_params(1);
+    assert!(h.withdraw_erc20_with(400, tx_params).is_err());
+
+    assert_eq!(h.erc20_balance(), 1_000);
+    let tx_params = h.withdrawal_tx_params(0);
+    h.withdraw_erc20_with(400, tx_params).unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+}
+
+#[test]
+fn native_transfers_outside_gas_limits_are_rejected() {
+    let mut h = Harness::new();
+
+    let mut tx_params = h.next_tx_params(5_000);
+    tx_params.gas_limit = MAX_GAS_LIMIT + 1;
+    assert!(h.deposit_native_with(5_000, tx_params).is_err());
+
+    let request_id = h.deposit_native(5_000);
+    h.claim_native(request_id, value_transfer_output()).unwrap();
+
+    let mut tx_params = h.withdrawal_tx_params(2_000);
+    tx_params.gas_limit = MAX_GAS_LIMIT + 1;
+    assert!(h.withdraw_native_with(2_000, tx_params).is_err());
+
+    let mut tx_params = h.withdrawal_tx_params(2_000);
+    tx_params.max_fee_per_gas = MAX_FEE_PER_GAS + 1;
+    assert!(h.withdraw_native_with(2_000, tx_params).is_err());
+
+    assert_eq!(h.native_balance(), 5_000);
+    let tx_params = h.withdrawal_tx_params(2_000);
+    h.withdraw_native_with(2_000, tx_params).unwrap();
+    assert_eq!(h.native_balance(), 3_000);
+}
+
+#[test]
+fn protocol_fees_are_withheld_and_collected_by_the_fee_authority() {
+    let mut h = Harness::new();
+
+    // 1% with a floor of 5
+    let authority = h.authority.pubkey();
+    h.send_as_authority(instructions::update_token(
+        authority,
+        args::UpdateToken {
+            chain_id: CHAIN_ID,
+            erc20_address: ERC20_ADDRESS,
+            params: TokenConfigParams {
+                fee_bps: 100,
+                min_fee: 5,
+                ..h.token_params("USDC")
+            },
+        },
+    ))
+    .unwrap();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    assert_eq!(h.erc20_balance(), 990);
+    assert_eq!(h.erc20_fee_balance(), 10);
+
+    // The full amount is debited, the transfer only sends it net of the fee
+    let request_id = h.withdraw_erc20(100).unwrap();
+    let pending: PendingErc20Withdrawal = h
+        .account(&pda::pending_erc20_withdrawal(&request_id).0)
+        .unwrap();
+    assert_eq!(pending.fee, 5);
+    assert_eq!(
+        pending.rlp_encoded_tx,
+        evm::build_erc20_transfer(ERC20_ADDRESS, EVM_RECIPIENT, 95, &pending.tx_params)
+    );
+    assert_eq!(h.erc20_balance(), 890);
+
+    h.complete_withdraw_erc20(request_id, transfer_output(true))
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 890);
+    assert_eq!(h.erc20_fee_balance(), 15);
+
+    // Amounts the fee would swallow whole are rejected
+    assert!(h.withdraw_erc20(5).is_err());
+    assert_eq!(h.erc20_balance(), 890);
+
+    // Only the fee authority collects, into its own balance
+    let user = h.user.insecure_clone();
+    assert!(h.collect_fees(15, &user).is_err());
+    let fee_authority = h.authority.insecure_clone();
+    assert!(h.collect_fees(16, &fee_authority).is_err());
+    h.collect_fees(15, &fee_authority).unwrap();
+    assert_eq!(h.erc20_fee_balance(), 0);
+    assert_eq!(h.erc20_balance_of(&fee_authority.pubkey()), 15);
+}
+
+#[test]
+fn withdrawals_are_rate_limited_per_user() {
+    let mut h = Harness::new();
+    set_user_withdrawal_limit(&mut h, 500, 1_000, 0);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    // The full limit is used up on the last slot of a window
+    let slot = h.slot();
+    h.warp_slots(999 - slot % 1_000);
+    h.withdraw_erc20(500).unwrap();
+    assert!(h.withdraw_erc20(1).is_err());
+
+    // The next window still counts it, weighted by how much of the trailing window it covers
+    h.warp_slots(1);
+    assert!(h.withdraw_erc20(1).is_err());
+
+    h.warp_slots(500);
+    assert!(h.withdraw_erc20(251).is_err());
+    h.withdraw_erc20(250).unwrap();
+    assert_eq!(h.erc20_balance(), 250);
+}
+
+#[test]
+fn withdrawals_that_never_went_out_release_their_rate_limit() {
+    let mut h = Harness::new();
+    // One window covers the refund delay
+    set_user_withdrawal_limit(&mut h, 500, 10 * WITHDRAWAL_REFUND_DELAY_SLOTS, 0);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    h.complete_withdraw_erc20(request_id, failure_output())
+        .unwrap();
+    let request_id = h.withdraw_erc20(500).unwrap();
+    assert!(h.withdraw_erc20(1).is_err());
+
+    h.warp_slots(WITHDRAWAL_REFUND_DELAY_SLOTS);
+    h.refund_expired_withdrawal(request_id).unwrap();
+    h.withdraw_erc20(500).unwrap();
+    assert_eq!(h.erc20_balance(), 500);
+}
+
+#[test]
+fn queued_withdrawals_count_against_the_rate_limit() {
+    let mut h = Harness::new();
+    set_user_withdrawal_limit(&mut h, 500, 1_000_000, 300);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let request_id = h.withdraw_erc20(400).unwrap();
+    assert!(h.withdraw_erc20(200).is_err());
+
+    h.cancel_queued_withdrawal(request_id).unwrap();
+    h.withdraw_erc20(500).unwrap();
+    assert_eq!(h.erc20_balance(), 500);
+}
+
+fn set_user_withdrawal_limit(
+    h: &mut Harness,
+    limit: u128,
+    window_slots: u64,
+    large_withdrawal_threshold: u128,
+) {
+    let authority = h.authority.pubkey();
+    h.send_as_authority(instructions::update_token(
+        authority,
+        args::UpdateToken {
+            chain_id: CHAIN_ID,
+            erc20_address: ERC20_ADDRESS,
+            params: TokenConfigParams {
+                user_withdrawal_limit: limit,
+                rate_limit_window_slots: window_slots,
+                large_withdrawal_threshold,
+                ..h.token_params("USDC")
+            },
+        },
+    ))
+    .unwrap();
+}
+
+fn set_large_withdrawal_threshold(h: &mut Harness, threshold: u128) {
+    let authority = h.authority.pubkey();
+    h.send_as_authority(instructions::update_token(
+        authority,
+        args::UpdateToken {
+            chain_id: CHAIN_ID,
+            erc20_address: ERC20_ADDRESS,
+            params: TokenConfigParams {
+                large_withdrawal_threshold: threshold,
+                ..h.token_params("USDC")
+            },
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn large_withdrawal_is_signed_only_after_the_delay() {
+    let mut h = Harness::new();
+    set_large_withdrawal_threshold(&mut h, 500);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    // Queued withdrawals are debited but leave the nonce to the ones behind them
+    let nonce = h.withdrawal_tx_params(0).nonce;
+    let request_id = h.withdraw_erc20(600).unwrap();
+    assert_eq!(h.erc20_balance(), 400);
+    assert_eq!(h.withdrawal_tx_params(0).nonce, nonce);
+
+    assert!(h.execute_queued_withdrawal(request_id).is_err());
+    assert!(h
+        .complete_withdraw_erc20(request_id, transfer_output(true))
+        .is_err());
+
+    h.warp_slots(LARGE_WITHDRAWAL_DELAY_SLOTS);
+    let signed_request_id = h.execute_queued_withdrawal(request_id).unwrap();
+    assert_eq!(h.withdrawal_tx_params(0).nonce, nonce + 1);
+
+    h.respond_to_withdrawal(request_id, signed_request_id, transfer_output(true))
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 400);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn delegate_executes_a_queued_withdrawal_after_the_delay() {
+    let mut h = Harness::new();
+    set_large_withdrawal_threshold(&mut h, 500);
+    let delegate = h.authority.insecure_clone();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    let expires_at_slot = h.slot() + 1_000;
+    h.approve_withdrawal(delegate.pubkey(), 600, expires_at_slot)
+        .unwrap();
+
+    // Execution is open to anyone, only the delay holds it back
+    let request_id = h.delegated_withdraw_erc20(600).unwrap();
+    assert!(h
+        .execute_queued_withdrawal_as(request_id, &delegate)
+        .is_err());
+
+    h.warp_slots(LARGE_WITHDRAWAL_DELAY_SLOTS);
+    let signed_request_id = h
+        .execute_queued_withdrawal_as(request_id, &delegate)
+        .unwrap();
+    h.respond_to_withdrawal(request_id, signed_request_id, transfer_output(true))
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 400);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+}
+
+#[test]
+fn guardian_can_cancel_a_queued_withdrawal() {
+    let mut h = Harness::new();
+    set_large_withdrawal_threshold(&mut h, 500);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    // Withdrawals up to the threshold are signed right away and cannot be cancelled
+    let small_id = h.withdraw_erc20(100).unwrap();
+    assert!(h.cancel_queued_withdrawal(small_id).is_err());
+
+    let request_id = h.withdraw_erc20(600).unwrap();
+    assert_eq!(h.erc20_balance(), 300);
+
+    let pending: PendingErc20Withdrawal = h
+        .account(&pda::pending_erc20_withdrawal(&request_id).0)
+        .unwrap();
+    let user = h.user.pubkey();
+    assert!(h
+        .send_as_user(instructions::cancel_queued_withdrawal(
+            user,
+            &pending,
+            args::CancelQueuedWithdrawal { request_id },
+        ))
+        .is_err());
+
+    h.cancel_queued_withdrawal(request_id).unwrap();
+    assert_eq!(h.erc20_balance(), 900);
+    assert_eq!(h.request_status(&request_id), RequestStatus::Cancelled);
+}
+
+#[test]
+fn erc20_balance_transfers_to_a_new_recipient() {
+    let mut h = Harness::new();
+    let recipient = Pubkey::new_unique();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    h.transfer_erc20_balance(recipient, 300).unwrap();
+    assert_eq!(h.erc20_balance(), 700);
+    assert_eq!(h.erc20_balance_of(&recipient), 300);
+
+    h.transfer_erc20_balance(recipient, 200).unwrap();
+    assert_eq!(h.erc20_balance_of(&recipient), 500);
+
+    assert!(h.transfer_erc20_balance(recipient, 501).is_err());
+    let user = h.user.pubkey();
+    assert!(h.transfer_erc20_balance(user, 100).is_err());
+    assert_eq!(h.erc20_balance(), 500);
+}
+
+#[test]
+fn erc20_balance_wraps_into_spl_tokens_and_back() {
+    let mut h = Harness::new();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    assert!(h.wrap_erc20_balance(400).is_err());
+    h.create_wrapped_mint().unwrap();
+
+    h.wrap_erc20_balance(400).unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+    assert_eq!(h.wrapped_token_balance(), 400);
+
+    assert!(h.unwrap_erc20_balance(401).is_err());
+    h.unwrap_erc20_balance(150).unwrap();
+    assert_eq!(h.erc20_balance(), 750);
+    assert_eq!(h.wrapped_token_balance(), 250);
+}
+
+#[test]
+fn wrapped_mint_caps_decimals_and_rejects_dust() {
+    let mut h = Harness::new();
+
+    let authority = h.authority.pubkey();
+    h.send_as_authority(instructions::update_token(
+        authority,
+        args::UpdateToken {
+            chain_id: CHAIN_ID,
+            erc20_address: ERC20_ADDRESS,
+            params: TokenConfigParams {
+                decimals: 18,
+                ..h.token_params("USDC")
+            },
+        },
+    ))
+    .unwrap();
+
+    let whole = 10u128.pow(18);
+    let deposit_id = h.deposit_erc20(5 * whole);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    h.create_wrapped_mint().unwrap();
+    assert_eq!(h.wrapped_mint_decimals(), 9);
+
+    // One wrapped unit is 10^9 of the 18 decimal balance, anything finer stays behind
+    let unit = 10u128.pow(9);
+    assert!(h.wrap_erc20_balance(2 * whole + 1).is_err());
+    h.wrap_erc20_balance(2 * whole).unwrap();
+    assert_eq!(h.erc20_balance(), 3 * whole);
+    assert_eq!(h.wrapped_token_balance(), 2 * 10u64.pow(9));
+
+    assert!(h.unwrap_erc20_balance(unit - 1).is_err());
+    h.unwrap_erc20_balance(unit).unwrap();
+    assert_eq!(h.erc20_balance(), 3 * whole + unit);
+    assert_eq!(h.wrapped_token_balance(), 2 * 10u64.pow(9) - 1);
+}
+
+#[test]
+fn wrapped_deposits_mint_on_claim_and_wrapped_withdrawals_burn() {
+    let mut h = Harness::new();
+    h.create_wrapped_mint().unwrap();
+
+    // The claim mints into the requester's token account, which has to exist by then
+    let deposit_id = h.deposit_wrapped_erc20(1_000);
+    assert!(h.claim_erc20(deposit_id, transfer_output(true)).is_err());
+    h.create_wrapped_token_account().unwrap();
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.wrapped_token_balance(), 1_000);
+
+    // Withdrawals burn the tokens, a failed transfer refunds into the balance
+    assert!(h.withdraw_wrapped_erc20(1_001).is_err());
+    let request_id = h.withdraw_wrapped_erc20(400).unwrap();
+    assert_eq!(h.erc20_balance(), 0);
+    assert_eq!(h.wrapped_token_balance(), 600);
+
+    h.complete_withdraw_erc20(request_id, failure_output())
+        .unwrap();
+    assert_eq!(h.erc20_balance(), 400);
+    assert_eq!(h.wrapped_token_balance(), 600);
+}
+
+#[test]
+fn delegate_spends_the_owners_balance_up_to_its_allowance() {
+    let mut h = Harness::new();
+    let delegate = h.authority.pubkey();
+    let recipient = Pubkey::new_unique();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    assert!(h.delegated_transfer_erc20_balance(recipient, 100).is_err());
+
+    let expires_at_slot = h.slot() + 1_000;
+    h.approve_withdrawal(delegate, 500, expires_at_slot)
+        .unwrap();
+
+    h.delegated_transfer_erc20_balance(recipient, 200).unwrap();
+    assert_eq!(h.erc20_balance(), 800);
+    assert_eq!(h.erc20_balance_of(&recipient), 200);
+
+    // The withdrawal is made for the owner, it settles against their balance
+    let request_id = h.delegated_withdraw_erc20(250).unwrap();
+    assert_eq!(h.erc20_balance(), 550);
+    h.complete_withdraw_erc20(request_id, transfer_output(true))
+        .unwrap();
+    assert_eq!(h.request_status(&request_id), RequestStatus::Claimed);
+
+    assert!(h.delegated_transfer_erc20_balance(recipient, 51).is_err());
+    h.delegated_transfer_erc20_balance(recipient, 50).unwrap();
+
+    h.approve_withdrawal(delegate, 100, expires_at_slot)
+        .unwrap();
+    h.warp_slots(1_000);
+    assert!(h.delegated_transfer_erc20_balance(recipient, 10).is_err());
+
+    h.revoke_withdrawal_allowance(delegate).unwrap();
+    assert!(h.delegated_transfer_erc20_balance(recipient, 10).is_err());
+    assert_eq!(h.erc20_balance(), 500);
+}
+
+#[test]
+fn only_the_upgrade_authority_can_initialize_the_config() {
+    let mut h = Harness::deploy();
+
+    let user = h.user.pubkey();
+    let params = h.config_params();
+    let result = h.send_as_user(instructions::initialize_config(
+        user,
+        args::InitializeConfig { params },
+    ));
+    assert!(result.is_err());
+
+    h.initialize();
+    assert_eq!(h.vault_config().authority, h.authority.pubkey());
+}
+
+#[test]
+fn compressed_request_record_leaves_a_tombstone() {
+    let mut h = Harness::new();
+    let user = h.user.insecure_clone();
+    let stranger = Keypair::new();
+    h.svm.airdrop(&stranger.pubkey(), 1_000_000_000).unwrap();
+
+    let request_id = h.deposit_erc20(1_000);
+    assert!(h.compress_request_record(request_id, &user).is_err());
+    h.claim_erc20(request_id, transfer_output(true)).unwrap();
+
+    assert!(h.compress_request_record(request_id, &stranger).is_err());
+    h.compress_request_record(request_id, &user).unwrap();
+
+    // The address stays taken, the request id can never be opened or claimed again
+    let tombstone: RequestTombstone = h
+        .account(&pda::request_record(&request_id).0)
+        .expect("tombstone missing");
+    assert_eq!(tombstone.request_id, request_id);
+    assert_eq!(tombstone.status, RequestStatus::Claimed);
+}
+
+#[test]
+fn each_pause_flag_blocks_its_instruction() {
+    let mut h = Harness::new();
+    let authority = h.authority.insecure_clone();
+
+    let deposit_id = h.deposit_erc20(1_000);
+    let claims = PauseFlags {
+        claims: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(claims, &authority).unwrap();
+    assert!(h.claim_erc20(deposit_id, transfer_output(true)).is_err());
+    h.set_pause_flags(PauseFlags::default(), &authority)
+        .unwrap();
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let deposits = PauseFlags {
+        deposits: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(deposits, &authority).unwrap();
+    let tx_params = h.next_tx_params(1_000);
+    assert!(h.deposit_native_with(1_000, tx_params).is_err());
+    h.set_pause_flags(PauseFlags::default(), &authority)
+        .unwrap();
+    let tx_params = h.next_tx_params(1_000);
+    h.deposit_native_with(1_000, tx_params).unwrap();
+
+    let withdrawals = PauseFlags {
+        withdrawals: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(withdrawals, &authority).unwrap();
+    assert!(h.withdraw_erc20(100).is_err());
+
+    h.set_pause_flags(PauseFlags::default(), &authority)
+        .unwrap();
+    let request_id = h.withdraw_erc20(100).unwrap();
+
+    // A paused completion holds the settlement back until the flag is lifted
+    let completions = PauseFlags {
+        completions: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(completions, &authority).unwrap();
+    assert!(h
+        .complete_withdraw_erc20(request_id, transfer_output(true))
+        .is_err());
+    h.set_pause_flags(PauseFlags::default(), &authority)
+        .unwrap();
+    h.complete_withdraw_erc20(request_id, transfer_output(true))
+        .unwrap();
+
+    let all = PauseFlags {
+        all: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(all, &authority).unwrap();
+    assert!(h.withdraw_erc20(100).is_err());
+    assert_eq!(h.erc20_balance(), 900);
+}
+
+#[test]
+fn refunds_and_cancels_go_through_while_paused() {
+    let mut h = Harness::new();
+    let authority = h.authority.insecure_clone();
+    set_large_withdrawal_threshold(&mut h, 500);
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+    let failed_id = h.withdraw_erc20(100).unwrap();
+    let expired_id = h.withdraw_erc20(200).unwrap();
+    let queued_id = h.withdraw_erc20(600).unwrap();
+    let unanswered_deposit_id = h.deposit_erc20(1_000);
+    assert_eq!(h.erc20_balance(), 100);
+
+    let all = PauseFlags {
+        all: true,
+        ..Default::default()
+    };
+    h.set_pause_flags(all, &authority).unwrap();
+
+    h.complete_withdraw_erc20(failed_id, failure_output())
+        .unwrap();
+    h.cancel_queued_withdrawal(queued_id).unwrap();
+    h.warp_slots(WITHDRAWAL_REFUND_DELAY_SLOTS);
+    h.refund_expired_withdrawal(expired_id).unwrap();
+    h.cancel_expired_deposit(unanswered_deposit_id).unwrap();
+
+    assert_eq!(h.erc20_balance(), 1_000);
+    assert_eq!(h.request_status(&failed_id), RequestStatus::Refunded);
+    assert_eq!(h.request_status(&queued_id), RequestStatus::Cancelled);
+    assert_eq!(h.request_status(&expired_id), RequestStatus::Expired);
+    assert_eq!(
+        h.request_status(&unanswered_deposit_id),
+        RequestStatus::Expired
+    );
+}
+
+#[test]
+fn guardian_can_only_tighten_pause_flags() {
+    let mut h = Harness::new();
+    let authority = h.authority.insecure_clone();
+    let guardian = Keypair::new();
+    h.svm.airdrop(&guardian.pubkey(), 1_000_000_000).unwrap();
+
+    let params = VaultConfigParams {
+        guardian: guardian.pubkey(),
+        ..h.config_params()
+    };
+    h.send_as_authority(instructions::update_config(
+        authority.pubkey(),
+        args::UpdateConfig {
+            params,
+            new_authority: None,
+        },
+    ))
+    .unwrap();
+
+    let deposits = PauseFlags {
+        deposits: true,
+        ..Default::default()
+    };
+    let deposits_and_claims = PauseFlags {
+        claims: true,
+        ..deposits
+    };
+    h.set_pause_flags(deposits, &guardian).unwrap();
+    h.set_pause_flags(deposits_and_claims, &guardian).unwrap();
+
+    // Lifting any flag, even while setting another, is left to the authority
+    let withdrawals = PauseFlags {
+        withdrawals: true,
+        ..Default::default()
+    };
+    assert!(h.set_pause_flags(withdrawals, &guardian).is_err());
+    assert!(h.set_pause_flags(PauseFlags::default(), &guardian).is_err());
+    let user = h.user.insecure_clone();
+    assert!(h.set_pause_flags(deposits_and_claims, &user).is_err());
+    assert_eq!(h.vault_config().pause, deposits_and_claims);
+
+    h.set_pause_flags(PauseFlags::default(), &authority)
+        .unwrap();
+    assert_eq!(h.vault_config().pause, PauseFlags::default());
+}
+
+fn set_key_rotation_grace_period(h: &mut Harness, seconds: i64) {
+    let authority = h.authority.pubkey();
+    let params = VaultConfigParams {
+        key_rotation_grace_period: seconds,
+        ..h.config_params()
+    };
+    h.send_as_authority(instructions::update_config(
+        authority,
+        args::UpdateConfig {
+            params,
+            new_authority: None,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn previous_key_is_trusted_only_inside_the_grace_window() {
+    let mut h = Harness::new();
+    set_key_rotation_grace_period(&mut h, 100);
+
+    let early_id = h.deposit_erc20(1_000);
+    let late_id = h.deposit_erc20(1_000);
+
+    let next_key = MockResponder::new(13);
+    h.register_mpc_signer(KEY_VERSION + 1, &next_key, &next_key)
+        .unwrap();
+    h.rotate_mpc_key(KEY_VERSION + 1).unwrap();
+
+    // Requests made under the previous key are still answered by it for a while
+    h.claim_erc20(early_id, transfer_output(true)).unwrap();
+    h.warp_seconds(100);
+    assert!(h.claim_erc20(late_id, transfer_output(true)).is_err());
+
+    // New requests are made under the new key and only its responses are accepted
+    let request_id = h.deposit_erc20(1_000);
+    assert!(h.claim_erc20(request_id, transfer_output(true)).is_err());
+    let output = transfer_output(true);
+    let signature = next_key.respond(&request_id, &output);
+    h.claim_erc20_with(request_id, output, signature).unwrap();
+    assert_eq!(h.erc20_balance(), 2_000);
+}
+
+#[test]
+fn revoked_key_is_rejected() {
+    let mut h = Harness::new();
+    set_key_rotation_grace_period(&mut h, 100);
+
+    let request_id = h.deposit_erc20(1_000);
+
+    // The current key cannot be revoked, only a rotated-out one
+    assert!(h.revoke_mpc_signer(KEY_VERSION).is_err());
+    let next_key = MockResponder::new(13);
+    h.register_mpc_signer(KEY_VERSION + 1, &next_key, &next_key)
+        .unwrap();
+    h.rotate_mpc_key(KEY_VERSION + 1).unwrap();
+
+    // Revocation cuts the grace window short
+    h.revoke_mpc_signer(KEY_VERSION).unwrap();
+    assert!(h.claim_erc20(request_id, transfer_output(true)).is_err());
+    assert_eq!(h.erc20_balance(), 0);
+}
+
+fn update_token_params(h: &mut Harness, params: TokenConfigParams) {
+    let authority = h.authority.pubkey();
+    h.send_as_authority(instructions::update_token(
+        authority,
+        args::UpdateToken {
+            chain_id: CHAIN_ID,
+            erc20_address: ERC20_ADDRESS,
+            params,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn unregistered_token_is_rejected() {
+    let mut h = Harness::new();
+
+    assert_vault_error(
+        h.deposit_erc20_with([0x2d; 20], 1_000, false),
+        ErrorCode::TokenNotRegistered,
+    );
+}
+
+#[test]
+fn disabled_token_rejects_deposits_and_withdrawals() {
+    let mut h = Harness::new();
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let params = TokenConfigParams {
+        enabled: false,
+        ..h.token_params("USDC")
+    };
+    update_token_params(&mut h, params);
+
+    assert_vault_error(
+        h.deposit_erc20_with(ERC20_ADDRESS, 1_000, false),
+        ErrorCode::TokenDisabled,
+    );
+    assert_vault_error(h.withdraw_erc20(500), ErrorCode::TokenDisabled);
+    assert_eq!(h.erc20_balance(), 1_000);
+}
+
+#[test]
+fn amounts_outside_token_bounds_are_rejected() {
+    let mut h = Harness::new();
+    let params = TokenConfigParams {
+        min_deposit: 100,
+        max_deposit: 1_000,
+        min_withdrawal: 50,
+        max_withdrawal: 500,
+        ..h.token_params("USDC")
+    };
+    update_token_params(&mut h, params);
+
+    assert_vault_error(
+        h.deposit_erc20_with(ERC20_ADDRESS, 99, false),
+        ErrorCode::AmountBelowMinimum,
+    );
+    assert_vault_error(
+        h.deposit_erc20_with(ERC20_ADDRESS, 1_001, false),
+        ErrorCode::AmountAboveMaximum,
+    );
+
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    assert_vault_error(h.withdraw_erc20(49), ErrorCode::AmountBelowMinimum);
+    assert_vault_error(h.withdraw_erc20(501), ErrorCode::AmountAboveMaximum);
+
+    h.withdraw_erc20(500).unwrap();
+    assert_eq!(h.erc20_balance(), 500);
+}
+
+const OTHER_CHAIN_ID: u64 = 84_532;
+
+fn set_fungible_chain_ids(h: &mut Harness, fungible_chain_ids: Vec<u64>) {
+    let authority = h.authority.pubkey();
+    let params = VaultConfigParams {
+        fungible_chain_ids,
+        ..h.config_params()
+    };
+    h.send_as_authority(instructions::update_config(
+        authority,
+        args::UpdateConfig {
+            params,
+            new_authority: None,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn withdrawal_to_another_chain_needs_both_chains_fungible() {
+    let mut h = Harness::new();
+    h.register_chain(OTHER_CHAIN_ID);
+    let deposit_id = h.deposit_erc20(1_000);
+    h.claim_erc20(deposit_id, transfer_output(true)).unwrap();
+
+    let tx_params = EvmTransactionParams {
+        chain_id: OTHER_CHAIN_ID,
+        ..h.tx_params(0, 0)
+    };
+    assert_vault_error(
+        h.withdraw_erc20_with(400, tx_params.clone()),
+        ErrorCode::ChainMismatch,
+    );
+
+    set_fungible_chain_ids(&mut h, vec![OTHER_CHAIN_ID]);
+    assert_vault_error(
+        h.withdraw_erc20_with(400, tx_params.clone()),
+        ErrorCode::ChainMismatch,
+    );
+
+    set_fungible_chain_ids(&mut h, vec![CHAIN_ID, OTHER_CHAIN_ID]);
+    let request_id = h.withdraw_erc20_with(400, tx_params).unwrap();
+    assert_eq!(h.erc20_balance(), 600);
+
+    let pending: PendingErc20Withdrawal = h
+        .account(&pda::pending_erc20_withdrawal(&request_id).0)
+        .unwrap();
+    assert_eq!(pending.source_chain_id, CHAIN_ID);
+    assert_eq!(pending.chain_id, OTHER_CHAIN_ID);
+}
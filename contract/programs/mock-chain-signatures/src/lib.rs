@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use chain_signatures::SerializationFormat;
+
+declare_id!("5xJXG3Gc5skTchRCk5vkk5SH1ypzTdkA7GEuYV4XvTZP");
+
+/// Accepts `sign_respond` with the same accounts and arguments as the real
+/// chain signatures program, without taking a deposit. Responses are produced
+/// off-chain by the test responder, so nothing is stored here.
+#[program]
+pub mod mock_chain_signatures {
+    use super::*;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_respond(
+        ctx: Context<SignRespond>,
+        serialized_transaction: Vec<u8>,
+        slip44_chain_id: u32,
+        key_version: u32,
+        path: String,
+        _algo: String,
+        _dest: String,
+        _params: String,
+        _explorer_deserialization_format: SerializationFormat,
+        _explorer_deserialization_schema: Vec<u8>,
+        _callback_serialization_format: SerializationFormat,
+        _callback_serialization_schema: Vec<u8>,
+    ) -> Result<()> {
+        msg!(
+            "Mock sign_respond from {} ({} byte tx, slip44 {}, key version {}, path {})",
+            ctx.accounts.requester.key(),
+            serialized_transaction.len(),
+            slip44_chain_id,
+            key_version,
+            path
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SignRespond<'info> {
+    /// CHECK: Not initialized in the mock
+    #[account(mut)]
+    pub program_state: UncheckedAccount<'info>,
+    pub requester: Signer<'info>,
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Instructions sysvar, unused by the mock
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// CHECK: Event authority of the real program, unused by the mock
+    pub event_authority: UncheckedAccount<'info>,
+    /// CHECK: Program account passed for CPI events, unused by the mock
+    pub program: UncheckedAccount<'info>,
+}
@@ -1,5 +1,5 @@
-// Chain Signatures Program ID on devnet
-pub const CHAIN_SIGNATURES_PROGRAM_ID: &str = "4uvZW8K4g4jBg7dzPNbb9XDxJLFBK7V6iC76uofmYvEU";
+// Token registry address used for native ETH
+pub const NATIVE_TOKEN_ADDRESS: [u8; 20] = [0u8; 20];
 
 // Chain signatures program state seeds
 pub const CHAIN_SIGNATURES_STATE_SEED: &[u8] = b"program-state";
@@ -3,17 +3,6 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
 use anchor_lang::solana_program::instruction::AccountMeta;
 
-#[derive(Clone)]
-pub struct ChainSignatures;
-
-impl anchor_lang::Id for ChainSignatures {
-    fn id() -> Pubkey {
-        crate::constants::CHAIN_SIGNATURES_PROGRAM_ID
-            .parse()
-            .expect("Invalid chain signatures program ID")
-    }
-}
-
 #[derive(Accounts)]
 pub struct SignAccounts<'info> {
     /// CHECK: This is the chain signatures program state account
@@ -38,7 +27,8 @@ pub mod cpi {
         ctx: CpiContext<'_, '_, '_, 'info, SignAccounts<'info>>,
         request: SignatureRequest,
     ) -> Result<()> {
-        let instruction = build_sign_instruction(&ctx.accounts, &request)?;
+        // The program the context was built for, the one recorded in the vault config
+        let instruction = build_sign_instruction(ctx.program.key, &ctx.accounts, &request)?;
 
         let mut account_infos = Vec::with_capacity(4);
         account_infos.push(ctx.accounts.program_state.to_account_info());
@@ -59,6 +49,7 @@ pub mod cpi {
     }
 
     fn build_sign_instruction(
+        program_id: &Pubkey,
         accounts: &SignAccounts,
         request: &SignatureRequest,
     ) -> Result<anchor_lang::solana_program::instruction::Instruction> {
@@ -86,7 +77,7 @@ pub mod cpi {
             .map_err(|_| ErrorCode::SerializationError)?;
 
         Ok(anchor_lang::solana_program::instruction::Instruction {
-            program_id: ChainSignatures::id(),
+            program_id: *program_id,
             accounts: account_metas,
             data,
         })
@@ -26,4 +26,82 @@ pub enum ErrorCode {
     InsufficientBalance,
     #[msg("Underflow error")]
     Underflow,
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+    #[msg("Derivation path exceeds maximum length")]
+    PathTooLong,
+    #[msg("Invalid config value")]
+    InvalidConfig,
+    #[msg("MPC signer for this key version is no longer trusted")]
+    MpcSignerNotTrusted,
+    #[msg("Invalid MPC key version")]
+    InvalidKeyVersion,
+    #[msg("This vault operation is paused")]
+    OperationPaused,
+    #[msg("Token is not registered with the vault")]
+    TokenNotRegistered,
+    #[msg("Token is disabled")]
+    TokenDisabled,
+    #[msg("Amount is below the token minimum")]
+    AmountBelowMinimum,
+    #[msg("Amount is above the token maximum")]
+    AmountAboveMaximum,
+    #[msg("Balance cannot be withdrawn on this chain")]
+    ChainMismatch,
+    #[msg("Transaction value does not match the transfer")]
+    InvalidTransactionValue,
+    #[msg("Pending request has not expired yet")]
+    RequestNotExpired,
+    #[msg("Request has already been settled")]
+    RequestAlreadySettled,
+    #[msg("Request is still pending")]
+    RequestStillPending,
+    #[msg("Invalid EVM transaction payload")]
+    InvalidTransaction,
+    #[msg("Signed transaction has already been recorded")]
+    SignedTransactionAlreadyRecorded,
+    #[msg("Request has already responded")]
+    RequestAlreadyResponded,
+    #[msg("Replacement must keep the nonce, chain and value of the original transaction")]
+    ReplacementMismatch,
+    #[msg("Replacement fees must be at least 10% higher")]
+    FeeBumpTooLow,
+    #[msg("Maximum number of fee bumps reached")]
+    TooManyFeeBumps,
+    #[msg("Nonce is not the next nonce of the signing address")]
+    InvalidNonce,
+    #[msg("Chain has no gas limits configured")]
+    ChainNotConfigured,
+    #[msg("Gas limit is above the chain maximum")]
+    GasLimitTooHigh,
+    #[msg("Gas fee is above the chain maximum")]
+    GasFeeTooHigh,
+    #[msg("Amount does not cover the protocol fee")]
+    AmountBelowFee,
+    #[msg("Fee balance account is required when a fee is charged")]
+    FeeBalanceMissing,
+    #[msg("Withdrawal exceeds the token's rolling rate limit")]
+    WithdrawalRateLimited,
+    #[msg("Withdrawal is queued and has not been sent for signing")]
+    WithdrawalQueued,
+    #[msg("Withdrawal is not queued")]
+    WithdrawalNotQueued,
+    #[msg("Queued withdrawal is still inside its delay")]
+    WithdrawalDelayNotElapsed,
+    #[msg("Invalid recipient")]
+    InvalidRecipient,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Spending another user's balance requires their allowance")]
+    AllowanceMissing,
+    #[msg("Allowance has expired")]
+    AllowanceExpired,
+    #[msg("Amount exceeds the remaining allowance")]
+    AllowanceExceeded,
+    #[msg("Amount is not a whole number of wrapped token units")]
+    WrappedAmountNotExact,
+    #[msg("Wrapped mode requires the wrapped mint, token account and token programs")]
+    WrappedAccountsMissing,
+    #[msg("Token account does not hold the owner's wrapped tokens")]
+    InvalidWrappedTokenAccount,
 }
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+
+use crate::state::request::{RequestKind, RequestStatus};
+
+// Vault lifecycle events, emitted through self-CPI so indexers can rebuild
+// balances from transaction data alone. `token` is NATIVE_TOKEN_ADDRESS for
+// native ETH and `balance` is the requester's balance after the step.
+// Where a protocol fee applies, `amount` is gross and `net_amount` is
+// `amount - fee`, the part credited to the user or sent out on the EVM side.
+// ERC20 withdrawal events that report a balance key it by the chain it is
+// held on, `source_chain_id`, which may differ from the transfer's chain.
+// Withdrawals and transfers made by a delegate report the balance owner as
+// `requester` or `sender`.
+
+#[event]
+pub struct DepositRequested {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee: u128,
+    pub net_amount: u128,
+    pub balance: u128,
+}
+
+#[event]
+pub struct DepositClaimed {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee: u128,
+    pub net_amount: u128,
+    pub balance: u128,
+}
+
+/// The sweep transaction failed on the EVM side, nothing was credited
+#[event]
+pub struct DepositFailed {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub balance: u128,
+}
+
+/// `balance` is already net of the optimistic debit
+#[event]
+pub struct WithdrawalRequested {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee: u128,
+    pub net_amount: u128,
+    pub balance: u128,
+}
+
+#[event]
+pub struct WithdrawalCompleted {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee: u128,
+    pub net_amount: u128,
+    pub balance: u128,
+}
+
+/// Balance moved between two Solana users, nothing happened on the EVM side
+#[event]
+pub struct Erc20BalanceTransferred {
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub sender_balance: u128,
+    pub recipient_balance: u128,
+}
+
+/// Accrued protocol fees moved into the fee authority's balance, `fee_balance` is what is left
+#[event]
+pub struct FeesCollected {
+    pub fee_authority: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee_balance: u128,
+    pub balance: u128,
+}
+
+/// `delegate` may withdraw or transfer up to `amount` of the owner's balance
+#[event]
+pub struct WithdrawalApproved {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub expires_at_slot: u64,
+}
+
+#[event]
+pub struct WithdrawalAllowanceRevoked {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub remaining: u128,
+}
+
+/// Balance converted into the token's wrapped SPL mint
+#[event]
+pub struct Erc20BalanceWrapped {
+    pub owner: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub balance: u128,
+}
+
+/// Wrapped SPL tokens burned back into the balance
+#[event]
+pub struct Erc20BalanceUnwrapped {
+    pub owner: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub balance: u128,
+}
+
+/// Large withdrawal debited and held until `executable_at_slot`, nothing was sent for signing
+#[event]
+pub struct WithdrawalQueued {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub fee: u128,
+    pub net_amount: u128,
+    pub balance: u128,
+    pub executable_at_slot: u64,
+}
+
+/// Queued withdrawal sent for signing, responses arrive for `signed_request_id`
+#[event]
+pub struct QueuedWithdrawalExecuted {
+    pub request_id: [u8; 32],
+    pub signed_request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub chain_id: u64,
+    pub nonce: u64,
+}
+
+/// Queued withdrawal vetoed by the guardian, the balance was restored
+#[event]
+pub struct WithdrawalCancelled {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub balance: u128,
+}
+
+/// Emitted both for failed transfers and for withdrawals refunded after the delay
+#[event]
+pub struct WithdrawalRefunded {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub balance: u128,
+}
+
+/// A stuck withdrawal was re-requested with higher fees under `replacement_request_id`
+#[event]
+pub struct WithdrawalFeeBumped {
+    pub request_id: [u8; 32],
+    pub replacement_request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// MPC signature recorded for a withdrawal, `raw_transaction` is ready to broadcast
+#[event]
+pub struct WithdrawalSigned {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub tx_hash: [u8; 32],
+    pub raw_transaction: Vec<u8>,
+}
+
+/// Full contents of a settled request record at the time it was closed
+#[event]
+pub struct RequestRecordCompressed {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    pub kind: RequestKind,
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub status: RequestStatus,
+    pub output_hash: [u8; 32],
+    pub created_slot: u64,
+    pub completed_slot: u64,
+}
@@ -0,0 +1,163 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use chain_signatures::Signature;
+use omni_transaction::{TransactionBuilder, TxBuilder, EVM};
+
+use crate::state::vault::{EvmTransactionParams, IERC20};
+
+/// Unsigned RLP payload of an ERC20 `transfer(recipient, amount)`
+pub fn build_erc20_transfer(
+    erc20_address: [u8; 20],
+    recipient: [u8; 20],
+    amount: u128,
+    tx_params: &EvmTransactionParams,
+) -> Vec<u8> {
+    let call = IERC20::transferCall {
+        to: Address::from(recipient),
+        amount: U256::from(amount),
+    };
+
+    let evm_tx = TransactionBuilder::new::<EVM>()
+        .nonce(tx_params.nonce)
+        .to(erc20_address)
+        .value(tx_params.value)
+        .input(call.abi_encode())
+        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
+        .max_fee_per_gas(tx_params.max_fee_per_gas)
+        .gas_limit(tx_params.gas_limit)
+        .chain_id(tx_params.chain_id)
+        .build();
+
+    evm_tx.build_for_signing()
+}
+
+/// Unsigned RLP payload of a plain native value transfer
+pub fn build_value_transfer(to: [u8; 20], tx_params: &EvmTransactionParams) -> Vec<u8> {
+    let evm_tx = TransactionBuilder::new::<EVM>()
+        .nonce(tx_params.nonce)
+        .to(to)
+        .value(tx_params.value)
+        .input(vec![])
+        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
+        .max_fee_per_gas(tx_params.max_fee_per_gas)
+        .gas_limit(tx_params.gas_limit)
+        .chain_id(tx_params.chain_id)
+        .build();
+
+    evm_tx.build_for_signing()
+}
+
+// EIP-2718 type byte of dynamic fee transactions
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// Upper bound on the unsigned payloads built above, kept in pending withdrawals
+pub const MAX_UNSIGNED_TX_LEN: usize = 256;
+
+/// Signed EIP-1559 transaction as it is broadcast on the EVM side
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedEvmTransaction {
+    /// `0x02 || rlp([..fields, y_parity, r, s])`, the `eth_sendRawTransaction` payload
+    pub raw_transaction: Vec<u8>,
+    /// keccak256 of the raw transaction, the hash the EVM chain indexes it under
+    pub tx_hash: [u8; 32],
+}
+
+/// Hash the MPC network signs for an unsigned EIP-1559 payload
+pub fn signing_hash(unsigned_tx: &[u8]) -> [u8; 32] {
+    keccak::hash(unsigned_tx).to_bytes()
+}
+
+/// Appends the MPC signature to an unsigned EIP-1559 payload built by this module
+pub fn assemble_signed_transaction(
+    unsigned_tx: &[u8],
+    signature: &Signature,
+) -> Result<SignedEvmTransaction> {
+    require!(
+        unsigned_tx.first() == Some(&EIP1559_TX_TYPE),
+        crate::error::ErrorCode::InvalidTransaction
+    );
+    // Ethereum only carries the parity of R.y, recovery ids 2 and 3 cannot be expressed
+    require!(
+        signature.recovery_id < 2,
+        crate::error::ErrorCode::InvalidSignature
+    );
+
+    let fields = rlp_list_payload(&unsigned_tx[1..])?;
+
+    let mut payload = fields.to_vec();
+    rlp_append_uint(&mut payload, &[signature.recovery_id]);
+    rlp_append_uint(&mut payload, &signature.big_r.x);
+    rlp_append_uint(&mut payload, &signature.s);
+
+    let mut raw_transaction = Vec::with_capacity(payload.len() + 4);
+    raw_transaction.push(EIP1559_TX_TYPE);
+    rlp_append_list_header(&mut raw_transaction, payload.len());
+    raw_transaction.extend_from_slice(&payload);
+
+    let tx_hash = keccak::hash(&raw_transaction).to_bytes();
+
+    Ok(SignedEvmTransaction {
+        raw_transaction,
+        tx_hash,
+    })
+}
+
+// Returns the encoded fields of an RLP list that spans the whole input
+fn rlp_list_payload(encoded: &[u8]) -> Result<&[u8]> {
+    let (offset, len) = match encoded.first() {
+        Some(&prefix @ 0xc0..=0xf7) => (1, (prefix - 0xc0) as usize),
+        Some(&prefix @ 0xf8..=0xff) => {
+            let len_of_len = (prefix - 0xf7) as usize;
+            let len_bytes = encoded
+                .get(1..1 + len_of_len)
+                .ok_or(crate::error::ErrorCode::InvalidTransaction)?;
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |len, byte| (len << 8) | *byte as usize);
+            (1 + len_of_len, len)
+        }
+        _ => return err!(crate::error::ErrorCode::InvalidTransaction),
+    };
+
+    require!(
+        encoded.len() == offset + len,
+        crate::error::ErrorCode::InvalidTransaction
+    );
+
+    Ok(&encoded[offset..])
+}
+
+// Big-endian integer as an RLP string, leading zeros stripped
+fn rlp_append_uint(out: &mut Vec<u8>, be_bytes: &[u8]) {
+    let start = be_bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(be_bytes.len());
+    let value = &be_bytes[start..];
+
+    match value {
+        [byte] if *byte < 0x80 => out.push(*byte),
+        // Integers here are at most 32 bytes, always a short string
+        _ => {
+            out.push(0x80 + value.len() as u8);
+            out.extend_from_slice(value);
+        }
+    }
+}
+
+fn rlp_append_list_header(out: &mut Vec<u8>, payload_len: usize) {
+    if payload_len < 56 {
+        out.push(0xc0 + payload_len as u8);
+        return;
+    }
+
+    let len_bytes = payload_len.to_be_bytes();
+    let start = len_bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(len_bytes.len());
+    out.push(0xf7 + (len_bytes.len() - start) as u8);
+    out.extend_from_slice(&len_bytes[start..]);
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{WithdrawalAllowanceRevoked, WithdrawalApproved};
+use crate::{ApproveWithdrawal, RevokeWithdrawalAllowance};
+
+/// Sets how much of the caller's balance `delegate` may withdraw or transfer until
+/// `expires_at_slot`, replacing any previous allowance
+pub fn approve_withdrawal(
+    ctx: Context<ApproveWithdrawal>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    delegate: Pubkey,
+    amount: u128,
+    expires_at_slot: u64,
+) -> Result<()> {
+    let owner = ctx.accounts.authority.key();
+
+    // Owners already spend their own balance, an allowance to themselves would never be read
+    require!(delegate != owner, crate::error::ErrorCode::InvalidRecipient);
+    require!(
+        expires_at_slot > Clock::get()?.slot,
+        crate::error::ErrorCode::AllowanceExpired
+    );
+
+    let allowance = &mut ctx.accounts.allowance;
+    allowance.owner = owner;
+    allowance.delegate = delegate;
+    allowance.chain_id = chain_id;
+    allowance.erc20_address = erc20_address;
+    allowance.amount = amount;
+    allowance.expires_at_slot = expires_at_slot;
+
+    msg!(
+        "Approved {} to spend {} of ERC20 {:?} on chain {} until slot {}",
+        delegate,
+        amount,
+        erc20_address,
+        chain_id,
+        expires_at_slot
+    );
+
+    emit_cpi!(WithdrawalApproved {
+        owner,
+        delegate,
+        token: erc20_address,
+        chain_id,
+        amount,
+        expires_at_slot,
+    });
+
+    Ok(())
+}
+
+/// Closes an allowance back to its owner
+pub fn revoke_withdrawal_allowance(
+    ctx: Context<RevokeWithdrawalAllowance>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    delegate: Pubkey,
+) -> Result<()> {
+    msg!(
+        "Revoked allowance of {} on ERC20 {:?} on chain {}",
+        delegate,
+        erc20_address,
+        chain_id
+    );
+
+    emit_cpi!(WithdrawalAllowanceRevoked {
+        owner: ctx.accounts.authority.key(),
+        delegate,
+        token: erc20_address,
+        chain_id,
+        remaining: ctx.accounts.allowance.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{Erc20BalanceTransferred, FeesCollected};
+use crate::state::allowance::WithdrawalAllowance;
+use crate::state::config::PausableAction;
+use crate::{CollectFees, TransferErc20Balance};
+
+/// Moves a bridged balance to another Solana user without touching the EVM side, signed by the
+/// owner or spending a delegate's allowance
+pub fn transfer_erc20_balance(
+    ctx: Context<TransferErc20Balance>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    recipient: Pubkey,
+    amount: u128,
+) -> Result<()> {
+    // Balances leave the sender as they would on a withdrawal, the same breaker applies
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    let sender = ctx.accounts.owner.key();
+
+    // Both sides would be the same account, the credit would overwrite the debit
+    require!(
+        recipient != sender,
+        crate::error::ErrorCode::InvalidRecipient
+    );
+    require!(amount > 0, crate::error::ErrorCode::InvalidAmount);
+
+    WithdrawalAllowance::spend(
+        ctx.accounts.allowance.as_deref_mut(),
+        &sender,
+        &ctx.accounts.authority.key(),
+        amount,
+    )?;
+
+    let sender_balance = &mut ctx.accounts.sender_balance;
+    sender_balance.amount = sender_balance
+        .amount
+        .checked_sub(amount)
+        .ok_or(crate::error::ErrorCode::InsufficientBalance)?;
+
+    let recipient_balance = &mut ctx.accounts.recipient_balance;
+    recipient_balance.amount = recipient_balance
+        .amount
+        .checked_add(amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!(
+        "Transferred {} of ERC20 {:?} on chain {} from {} to {}",
+        amount,
+        erc20_address,
+        chain_id,
+        sender,
+        recipient
+    );
+
+    emit_cpi!(Erc20BalanceTransferred {
+        sender,
+        recipient,
+        token: erc20_address,
+        chain_id,
+        amount,
+        sender_balance: ctx.accounts.sender_balance.amount,
+        recipient_balance: ctx.accounts.recipient_balance.amount,
+    });
+
+    Ok(())
+}
+
+/// Moves accrued protocol fees into the fee authority's own balance, from where they are
+/// withdrawn like any other. Fees accrue independently of who the fee authority is, so
+/// rotating it hands over everything not yet collected
+pub fn collect_fees(
+    ctx: Context<CollectFees>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    amount: u128,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    require!(amount > 0, crate::error::ErrorCode::InvalidAmount);
+
+    let fee_balance = &mut ctx.accounts.fee_balance;
+    fee_balance.amount = fee_balance
+        .amount
+        .checked_sub(amount)
+        .ok_or(crate::error::ErrorCode::InsufficientBalance)?;
+
+    let recipient_balance = &mut ctx.accounts.recipient_balance;
+    recipient_balance.amount = recipient_balance
+        .amount
+        .checked_add(amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!(
+        "Collected {} of ERC20 {:?} fees on chain {}",
+        amount,
+        erc20_address,
+        chain_id
+    );
+
+    emit_cpi!(FeesCollected {
+        fee_authority: ctx.accounts.fee_authority.key(),
+        token: erc20_address,
+        chain_id,
+        amount,
+        fee_balance: ctx.accounts.fee_balance.amount,
+        balance: ctx.accounts.recipient_balance.amount,
+    });
+
+    Ok(())
+}
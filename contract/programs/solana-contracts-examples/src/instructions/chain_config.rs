@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::chain::ChainConfigParams;
+use crate::SetChainConfig;
+
+pub fn set_chain_config(
+    ctx: Context<SetChainConfig>,
+    chain_id: u64,
+    params: ChainConfigParams,
+) -> Result<()> {
+    let chain = &mut ctx.accounts.chain_config;
+    chain.chain_id = chain_id;
+    chain.apply(params)?;
+
+    msg!(
+        "Chain {} gas caps: limit {}, max fee {}, priority fee {}",
+        chain_id,
+        chain.max_gas_limit,
+        chain.max_fee_per_gas,
+        chain.max_priority_fee_per_gas
+    );
+
+    Ok(())
+}
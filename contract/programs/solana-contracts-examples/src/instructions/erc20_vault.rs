@@ -1,20 +1,34 @@
-use alloy_primitives::{Address, U256};
-use alloy_sol_types::SolCall;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
 use anchor_lang::solana_program::secp256k1_recover::secp256k1_recover;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use chain_signatures::cpi::accounts::SignRespond;
-use chain_signatures::cpi::sign_respond;
-use chain_signatures::SerializationFormat;
-use omni_transaction::{TransactionBuilder, TxBuilder, EVM};
 
-use crate::state::vault::{EvmTransactionParams, IERC20};
-use crate::{ClaimErc20, CompleteWithdrawErc20, DepositErc20, WithdrawErc20};
-
-const HARDCODED_RECIPIENT: &str = "0xdcF0f02E13eF171aA028Bc7d4c452CFCe3C2E18f";
-const MPC_ROOT_SIGNER_ADDRESS: &str = "0x00A40C2661293d5134E53Da52951A3F7767836Ef";
-const HARDCODED_ROOT_PATH: &str = "root";
+use crate::events::{
+    DepositClaimed, DepositFailed, DepositRequested, Erc20BalanceUnwrapped, Erc20BalanceWrapped,
+    WithdrawalCompleted, WithdrawalFeeBumped, WithdrawalQueued, WithdrawalRefunded,
+    WithdrawalRequested,
+};
+use crate::evm::{build_erc20_transfer, signing_hash};
+use crate::instructions::wrapped_token::{burn_wrapped, mint_wrapped};
+use crate::signing::{
+    evm_request_id, hash_message, request_evm_signature, SignRequestAccounts, SIGNATURE_ALGO,
+    SIGNATURE_DEST, SIGNATURE_PARAMS, SLIP44_ETHEREUM,
+};
+use crate::state::allowance::WithdrawalAllowance;
+use crate::state::chain::ChainConfig;
+use crate::state::config::{MpcSigner, PausableAction};
+use crate::state::request::{RequestKind, RequestStatus};
+use crate::state::token::TokenConfig;
+use crate::state::vault::{
+    EvmTransactionParams, PendingErc20Deposit, PendingErc20Withdrawal, UserErc20Balance, IERC20,
+};
+use crate::{
+    BumpWithdrawalFee, CancelExpiredDeposit, ClaimErc20, CompleteWithdrawErc20, DepositErc20,
+    RefundExpiredWithdrawal, WithdrawErc20,
+};
+
+// Magic prefix the MPC network puts on responses for transactions that failed on the EVM side
+pub const ERROR_PREFIX: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
 
 #[derive(BorshSerialize, BorshDeserialize, BorshSchema)]
 pub struct NonFunctionCallResult {
@@ -33,31 +47,36 @@ pub fn deposit_erc20(
     erc20_address: [u8; 20],
     amount: u128,
     tx_params: EvmTransactionParams,
+    relayer_tip: u64,
 ) -> Result<()> {
-    let path = requester.to_string();
-
-    // Create ERC20 transfer call
-    let recipient_bytes = hex::decode(&HARDCODED_RECIPIENT[2..])
-        .map_err(|_| crate::error::ErrorCode::InvalidAddress)?;
-    let recipient = Address::from_slice(&recipient_bytes);
-    let call = IERC20::transferCall {
-        to: recipient,
-        amount: U256::from(amount),
-    };
-
-    // Build EVM transaction
-    let evm_tx = TransactionBuilder::new::<EVM>()
-        .nonce(tx_params.nonce)
-        .to(erc20_address)
-        .value(tx_params.value)
-        .input(call.abi_encode())
-        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
-        .max_fee_per_gas(tx_params.max_fee_per_gas)
-        .gas_limit(tx_params.gas_limit)
-        .chain_id(tx_params.chain_id)
-        .build();
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Deposit)?;
+
+    let token = TokenConfig::load(&ctx.accounts.token_config)?;
+    token.require_deposit_allowed(amount)?;
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_token_transfer_allowed(&tx_params)?;
+
+    let fee = protocol_fee(
+        &token,
+        &ctx.accounts.config.fee_authority,
+        &requester,
+        amount,
+    )?;
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(crate::error::ErrorCode::AmountBelowFee)?;
 
-    let rlp_encoded_tx = evm_tx.build_for_signing();
+    let path = requester.to_string();
+    let key_version = ctx.accounts.config.current_key_version;
+
+    // Build an ERC20 transfer sweeping funds to the configured recipient
+    let rlp_encoded_tx = build_erc20_transfer(
+        erc20_address,
+        ctx.accounts.config.sweep_recipient,
+        amount,
+        &tx_params,
+    );
 
     // Add detailed logging
     msg!("=== REQUEST ID CALCULATION DEBUG ===");
@@ -67,23 +86,19 @@ pub fn deposit_erc20(
         "Transaction data (first 32 bytes): {:?}",
         &rlp_encoded_tx[..32.min(rlp_encoded_tx.len())]
     );
-    msg!("SLIP44 chain ID: {}", 60);
-    msg!("Key version: {}", 0);
+    msg!("SLIP44 chain ID: {}", SLIP44_ETHEREUM);
+    msg!("Key version: {}", key_version);
     msg!("Path: {}", path);
-    msg!("Algo: {}", "ECDSA");
-    msg!("Dest: {}", "ethereum");
-    msg!("Params: {}", "");
+    msg!("Algo: {}", SIGNATURE_ALGO);
+    msg!("Dest: {}", SIGNATURE_DEST);
+    msg!("Params: {}", SIGNATURE_PARAMS);
 
     // Generate request ID and verify it matches the one passed in
-    let computed_request_id = generate_sign_respond_request_id(
+    let computed_request_id = evm_request_id(
         &ctx.accounts.requester_pda.key(),
         &rlp_encoded_tx,
-        60, // Ethereum SLIP-44
-        0,  // key_version
+        key_version,
         &path,
-        "ECDSA",
-        "ethereum",
-        "",
     );
 
     msg!("Computed request ID: {:?}", computed_request_id);
@@ -97,26 +112,44 @@ pub fn deposit_erc20(
 
     // Store pending deposit info
     let pending = &mut ctx.accounts.pending_deposit;
+    pending.version = PendingErc20Deposit::LAYOUT_VERSION;
     pending.requester = requester;
     pending.amount = amount;
     pending.erc20_address = erc20_address;
+    pending.chain_id = tx_params.chain_id;
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.payer = ctx.accounts.payer.key();
+    pending.relayer_tip = relayer_tip;
+    pending.created_slot = Clock::get()?.slot;
+    pending.expires_at_slot = pending
+        .created_slot
+        .checked_add(ctx.accounts.config.deposit_timeout_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    pending.tx_params = tx_params.clone();
+    pending.payload_hash = signing_hash(&rlp_encoded_tx);
+    pending.fee = fee;
+    pending.wrapped = ctx.accounts.wrapped_mint.is_some();
+
+    ctx.accounts.request_record.open(
+        request_id,
+        requester,
+        ctx.accounts.payer.key(),
+        RequestKind::Erc20Deposit,
+        erc20_address,
+        tx_params.chain_id,
+        amount,
+        pending.created_slot,
+    );
 
-    // Create schema for ERC20 transfer return value from alloy-sol-types
-    let functions = IERC20::abi::functions();
-    let transfer_func = functions
-        .get("transfer")
-        .and_then(|funcs| funcs.first())
-        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
-
-    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
-
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    escrow_relayer_tip(
+        &ctx.accounts.payer,
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.system_program,
+        relayer_tip,
+    )?;
 
-    // CPI to sign_respond
     let requester_key_bytes = requester.to_bytes();
     let requester_bump = ctx.bumps.requester_pda;
     let signer_seeds: &[&[&[u8]]] = &[&[
@@ -125,45 +158,36 @@ pub fn deposit_erc20(
         &[requester_bump],
     ]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignRespond {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
             requester: ctx.accounts.requester_pda.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
         },
         signer_seeds,
-    );
-
-    sign_respond(
-        cpi_ctx,
         rlp_encoded_tx,
-        60, // Ethereum SLIP-44
-        0,  // key_version
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "ethereum".to_string(),
-        "".to_string(),
-        SerializationFormat::AbiJson,
-        explorer_schema,
-        SerializationFormat::Borsh,
-        callback_schema,
+        erc20_transfer_schemas()?,
     )?;
 
     msg!("ERC20 deposit initiated with request_id: {:?}", request_id);
 
+    emit_cpi!(DepositRequested {
+        request_id,
+        requester,
+        token: erc20_address,
+        chain_id: ctx.accounts.pending_deposit.chain_id,
+        amount,
+        fee,
+        net_amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
     Ok(())
 }
 
@@ -173,33 +197,102 @@ pub fn claim_erc20(
     serialized_output: Vec<u8>,
     signature: chain_signatures::Signature,
 ) -> Result<()> {
+    ctx.accounts.config.require_active(PausableAction::Claim)?;
+
     let pending = &ctx.accounts.pending_deposit;
 
     // Verify signature
     let message_hash = hash_message(&request_id, &serialized_output);
 
     // Verify the signature
-    verify_signature_from_address(&message_hash, &signature, MPC_ROOT_SIGNER_ADDRESS)?;
+    verify_mpc_response(&ctx.accounts.mpc_signer, &message_hash, &signature)?;
 
     msg!("Signature verified successfully");
 
-    // Deserialize directly as bool (server now sends just the boolean)
-    let success: bool = BorshDeserialize::try_from_slice(&serialized_output)
-        .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
+    let success = if is_error_response(&serialized_output) {
+        msg!("Detected error response (magic prefix)");
+        false
+    } else {
+        // Deserialize directly as bool (server now sends just the boolean)
+        BorshDeserialize::try_from_slice(&serialized_output)
+            .map_err(|_| crate::error::ErrorCode::InvalidOutput)?
+    };
 
-    require!(success, crate::error::ErrorCode::TransferFailed);
+    if success {
+        // Update user balance, net of the protocol fee
+        let net_amount = pending
+            .amount
+            .checked_sub(pending.fee)
+            .ok_or(crate::error::ErrorCode::Underflow)?;
 
-    // Update user balance
-    let balance = &mut ctx.accounts.user_balance;
-    balance.amount = balance
-        .amount
-        .checked_add(pending.amount)
-        .ok_or(crate::error::ErrorCode::Overflow)?;
+        // Wrapped deposits are minted, only the dust below one wrapped unit is credited
+        let credited = if pending.wrapped {
+            mint_claimed_deposit(ctx.accounts, net_amount)?
+        } else {
+            net_amount
+        };
 
-    msg!(
-        "ERC20 deposit claimed successfully. New balance: {}",
-        balance.amount
-    );
+        let balance = &mut ctx.accounts.user_balance;
+        balance.amount = balance
+            .amount
+            .checked_add(credited)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+
+        credit_fee(&mut ctx.accounts.fee_balance, pending.fee)?;
+
+        msg!(
+            "ERC20 deposit claimed successfully. New balance: {}",
+            balance.amount
+        );
+
+        emit_cpi!(DepositClaimed {
+            request_id,
+            requester: pending.requester,
+            token: pending.erc20_address,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            fee: pending.fee,
+            net_amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+
+        if credited < net_amount {
+            emit_cpi!(Erc20BalanceWrapped {
+                owner: pending.requester,
+                token: pending.erc20_address,
+                chain_id: pending.chain_id,
+                amount: net_amount - credited,
+                balance: ctx.accounts.user_balance.amount,
+            });
+        }
+    } else {
+        // The sweep failed on the EVM side, settle the record without crediting anything
+        msg!("ERC20 deposit failed, nothing credited");
+
+        emit_cpi!(DepositFailed {
+            request_id,
+            requester: pending.requester,
+            token: pending.erc20_address,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    }
+
+    let status = if success {
+        RequestStatus::Claimed
+    } else {
+        RequestStatus::Failed
+    };
+    ctx.accounts
+        .request_record
+        .settle(status, Some(&serialized_output))?;
+
+    pay_relayer_tip(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        pending.relayer_tip,
+    )?;
 
     Ok(())
 }
@@ -211,11 +304,70 @@ pub fn withdraw_erc20(
     amount: u128,
     recipient_address: [u8; 20],
     tx_params: EvmTransactionParams,
+    source_chain_id: u64,
+    relayer_tip: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    let token = TokenConfig::load(&ctx.accounts.token_config)?;
+    token.require_withdrawal_allowed(amount)?;
+
+    // Large withdrawals are held for the guardian to review, they count against the
+    // limits as soon as they are queued
+    let queued = token.requires_delay(amount);
+    token.consume_withdrawal_limits(
+        &mut ctx.accounts.token_rate_limit,
+        &mut ctx.accounts.user_rate_limit,
+        amount,
+    )?;
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_token_transfer_allowed(&tx_params)?;
+
+    // Only draw on liquidity from the same chain or one declared fungible with it
+    require!(
+        ctx.accounts
+            .config
+            .can_withdraw_across(source_chain_id, tx_params.chain_id),
+        crate::error::ErrorCode::ChainMismatch
+    );
+
+    // Delegates withdraw on the owner's behalf, the owner stays the requester and gets any
+    // refund while the delegate pays for the request
     let authority = ctx.accounts.authority.key();
+    let owner = ctx.accounts.owner.key();
+    WithdrawalAllowance::spend(
+        ctx.accounts.allowance.as_deref_mut(),
+        &owner,
+        &authority,
+        amount,
+    )?;
+    let fee = protocol_fee(&token, &ctx.accounts.config.fee_authority, &owner, amount)?;
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(crate::error::ErrorCode::AmountBelowFee)?;
+
+    // Use the configured root path for withdrawals
+    let path = ctx.accounts.config.withdrawal_root_path.clone();
+    let key_version = ctx.accounts.config.current_key_version;
+
+    // Withdrawing from wrapped tokens burns them back into the balance first
+    if ctx.accounts.wrapped_token_account.is_some() {
+        burn_withdrawn_wrapped(ctx.accounts, &token, amount)?;
+        let balance = &mut ctx.accounts.user_balance;
+        balance.amount = balance
+            .amount
+            .checked_add(amount)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
 
-    // Use the hardcoded root path for withdrawals
-    let path = HARDCODED_ROOT_PATH.to_string();
+        emit_cpi!(Erc20BalanceUnwrapped {
+            owner,
+            token: erc20_address,
+            chain_id: source_chain_id,
+            amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    }
 
     // Check user has sufficient balance
     let balance = &mut ctx.accounts.user_balance;
@@ -232,37 +384,23 @@ pub fn withdraw_erc20(
 
     msg!("Optimistically decremented balance by {}", amount);
 
-    // Create ERC20 transfer call
-    let recipient = Address::from_slice(&recipient_address);
-    let call = IERC20::transferCall {
-        to: recipient,
-        amount: U256::from(amount),
-    };
-
-    // Build EVM transaction - note: this is FROM the hardcoded recipient address
-    let evm_tx = TransactionBuilder::new::<EVM>()
-        .nonce(tx_params.nonce)
-        .to(erc20_address)
-        .value(tx_params.value)
-        .input(call.abi_encode())
-        .max_priority_fee_per_gas(tx_params.max_priority_fee_per_gas)
-        .max_fee_per_gas(tx_params.max_fee_per_gas)
-        .gas_limit(tx_params.gas_limit)
-        .chain_id(tx_params.chain_id)
-        .build();
+    // Withdrawals share one signing address, nonces are handed out in order. A queued
+    // withdrawal takes its nonce when executed so it does not hold up the ones behind it
+    if !queued {
+        ctx.accounts.nonce_tracker.consume(tx_params.nonce)?;
+    }
 
-    let rlp_encoded_tx = evm_tx.build_for_signing();
+    // Build the ERC20 transfer - note: this is FROM the withdrawal address of the current key.
+    // The full amount is debited but the fee stays in the vault
+    let rlp_encoded_tx =
+        build_erc20_transfer(erc20_address, recipient_address, net_amount, &tx_params);
 
     // Generate request ID
-    let computed_request_id = generate_sign_respond_request_id(
+    let computed_request_id = evm_request_id(
         &ctx.accounts.requester.key(),
         &rlp_encoded_tx,
-        60, // Ethereum SLIP-44
-        0,  // key_version
+        key_version,
         &path,
-        "ECDSA",
-        "ethereum",
-        "",
     );
 
     msg!("Computed request ID: {:?}", computed_request_id);
@@ -275,68 +413,103 @@ pub fn withdraw_erc20(
 
     // Store pending withdrawal info
     let pending = &mut ctx.accounts.pending_withdrawal;
-    pending.requester = authority;
+    pending.version = PendingErc20Withdrawal::LAYOUT_VERSION;
+    pending.requester = owner;
     pending.amount = amount;
     pending.erc20_address = erc20_address;
     pending.recipient_address = recipient_address;
+    pending.chain_id = tx_params.chain_id;
+    pending.source_chain_id = source_chain_id;
     pending.path = path.clone();
     pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.payer = authority;
+    pending.relayer_tip = relayer_tip;
+    pending.created_slot = Clock::get()?.slot;
+    pending.refundable_at_slot = pending
+        .created_slot
+        .checked_add(ctx.accounts.config.withdrawal_refund_delay_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    pending.rlp_encoded_tx = rlp_encoded_tx.clone();
+    pending.evm_tx_hash = [0u8; 32];
+    pending.tx_params = tx_params.clone();
+    pending.payload_hash = signing_hash(&rlp_encoded_tx);
+    pending.replacement_request_ids = Vec::new();
+    pending.responded = 0;
+    pending.fee = fee;
+    pending.previous_tx_params = Vec::new();
+    pending.recorded = 0;
+    // Withdrawals are sent from the withdrawal address of the signing key. It is pinned here
+    // so a later key rotation cannot redirect signature recording
+    pending.signer_address = ctx.accounts.current_signer.withdrawal_address;
+    if queued {
+        pending.executable_at_slot = pending
+            .created_slot
+            .checked_add(ctx.accounts.config.large_withdrawal_delay_slots)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+        pending.signed_request_id = [0u8; 32];
+    } else {
+        pending.executable_at_slot = 0;
+        pending.signed_request_id = request_id;
+    }
 
-    // Create schema for ERC20 transfer return value
-    let functions = IERC20::abi::functions();
-    let transfer_func = functions
-        .get("transfer")
-        .and_then(|funcs| funcs.first())
-        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
+    ctx.accounts.request_record.open(
+        request_id,
+        owner,
+        authority,
+        RequestKind::Erc20Withdrawal,
+        erc20_address,
+        tx_params.chain_id,
+        amount,
+        pending.created_slot,
+    );
 
-    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    escrow_relayer_tip(
+        &ctx.accounts.authority,
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.system_program,
+        relayer_tip,
+    )?;
 
-    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
-        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+    if queued {
+        msg!("ERC20 withdrawal queued with request_id: {:?}", request_id);
+
+        emit_cpi!(WithdrawalQueued {
+            request_id,
+            requester: owner,
+            token: erc20_address,
+            chain_id: ctx.accounts.pending_withdrawal.source_chain_id,
+            amount,
+            fee,
+            net_amount,
+            balance: ctx.accounts.user_balance.amount,
+            executable_at_slot: ctx.accounts.pending_withdrawal.executable_at_slot,
+        });
+
+        return Ok(());
+    }
 
-    // CPI to sign_respond
     let requester_bump = ctx.bumps.requester;
     let signer_seeds: &[&[&[u8]]] = &[&[
         b"global_vault_authority", // Just this seed
         &[requester_bump],
     ]];
 
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.chain_signatures_program.to_account_info(),
-        SignRespond {
-            program_state: ctx.accounts.chain_signatures_state.to_account_info(),
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
             requester: ctx.accounts.requester.to_account_info(),
-            fee_payer: ctx
-                .accounts
-                .fee_payer
-                .as_ref()
-                .map(|fp| fp.to_account_info()),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            instructions: ctx
-                .accounts
-                .instructions
-                .as_ref()
-                .map(|i| i.to_account_info()),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.chain_signatures_program.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
         },
         signer_seeds,
-    );
-
-    sign_respond(
-        cpi_ctx,
         rlp_encoded_tx,
-        60, // Ethereum SLIP-44
-        0,  // key_version
+        key_version,
         path,
-        "ECDSA".to_string(),
-        "ethereum".to_string(),
-        "".to_string(),
-        SerializationFormat::AbiJson,
-        explorer_schema,
-        SerializationFormat::Borsh,
-        callback_schema,
+        erc20_transfer_schemas()?,
     )?;
 
     msg!(
@@ -344,6 +517,17 @@ pub fn withdraw_erc20(
         request_id
     );
 
+    emit_cpi!(WithdrawalRequested {
+        request_id,
+        requester: owner,
+        token: erc20_address,
+        chain_id: ctx.accounts.pending_withdrawal.source_chain_id,
+        amount,
+        fee,
+        net_amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
     Ok(())
 }
 
@@ -353,18 +537,21 @@ pub fn complete_withdraw_erc20(
     serialized_output: Vec<u8>,
     signature: chain_signatures::Signature,
 ) -> Result<()> {
-    let pending = &ctx.accounts.pending_withdrawal;
+    // `request_id` is the request that responded, the original or one of its fee bumps
+    let response_bit = ctx.accounts.pending_withdrawal.response_bit(&request_id)?;
+    require!(
+        ctx.accounts.pending_withdrawal.responded & response_bit == 0,
+        crate::error::ErrorCode::RequestAlreadyResponded
+    );
 
     // Verify signature
     let message_hash = hash_message(&request_id, &serialized_output);
-    verify_signature_from_address(&message_hash, &signature, MPC_ROOT_SIGNER_ADDRESS)?;
+    verify_mpc_response(&ctx.accounts.mpc_signer, &message_hash, &signature)?;
 
     msg!("Signature verified successfully");
 
     // Check for error magic prefix
-    const ERROR_PREFIX: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
-
-    let should_refund = if serialized_output.len() >= 4 && &serialized_output[..4] == ERROR_PREFIX {
+    let should_refund = if is_error_response(&serialized_output) {
         msg!("Detected error response (magic prefix)");
         true // Always refund on error
     } else {
@@ -381,6 +568,27 @@ pub fn complete_withdraw_erc20(
         }
     };
 
+    // Refunds must always go through so funds never get stuck behind a pause
+    if !should_refund {
+        ctx.accounts
+            .config
+            .require_active(PausableAction::Completion)?;
+    }
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.responded |= response_bit;
+
+    // Linked requests share a nonce, a failed one says nothing about the others still in flight
+    if should_refund && !pending.all_responded() {
+        msg!(
+            "Linked request {:?} failed, waiting on the remaining requests",
+            request_id
+        );
+        return Ok(());
+    }
+
+    let original_request_id = pending.request_id;
+
     if should_refund {
         // Refund the balance
         let balance = &mut ctx.accounts.user_balance;
@@ -390,18 +598,432 @@ pub fn complete_withdraw_erc20(
             .ok_or(crate::error::ErrorCode::Overflow)?;
 
         msg!("Balance refunded: {}", pending.amount);
+
+        // The withdrawal never went out, give its capacity back
+        ctx.accounts
+            .token_rate_limit
+            .release(pending.amount, pending.created_slot);
+        ctx.accounts
+            .user_rate_limit
+            .release(pending.amount, pending.created_slot);
+
+        emit_cpi!(WithdrawalRefunded {
+            request_id: original_request_id,
+            requester: pending.requester,
+            token: pending.erc20_address,
+            chain_id: pending.source_chain_id,
+            amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    } else {
+        credit_fee(&mut ctx.accounts.fee_balance, pending.fee)?;
+
+        emit_cpi!(WithdrawalCompleted {
+            request_id: original_request_id,
+            requester: pending.requester,
+            token: pending.erc20_address,
+            chain_id: pending.source_chain_id,
+            amount: pending.amount,
+            fee: pending.fee,
+            net_amount: pending.net_amount()?,
+            balance: ctx.accounts.user_balance.amount,
+        });
     }
 
+    let status = if should_refund {
+        RequestStatus::Refunded
+    } else {
+        RequestStatus::Claimed
+    };
+    ctx.accounts
+        .request_record
+        .settle(status, Some(&serialized_output))?;
+
+    pay_relayer_tip(
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.pending_withdrawal.relayer_tip,
+    )?;
+
+    // Closed here rather than through the context, a failure with requests still in flight keeps it open
+    ctx.accounts
+        .pending_withdrawal
+        .close(ctx.accounts.rent_payer.to_account_info())?;
+
     msg!("ERC20 withdrawal process completed");
 
     Ok(())
 }
 
+/// Re-requests a stuck withdrawal with the same nonce and higher fees, whichever of the
+/// linked transactions lands settles the withdrawal
+pub fn bump_withdrawal_fee(
+    ctx: Context<BumpWithdrawalFee>,
+    request_id: [u8; 32],
+    replacement_request_id: [u8; 32],
+    tx_params: EvmTransactionParams,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+
+    require!(
+        !pending.is_queued(),
+        crate::error::ErrorCode::WithdrawalQueued
+    );
+    require!(
+        pending.replacement_request_ids.len() < PendingErc20Withdrawal::MAX_FEE_BUMPS,
+        crate::error::ErrorCode::TooManyFeeBumps
+    );
+    // Once any linked request responded its nonce is spent, a replacement could never land
+    require!(
+        pending.responded == 0,
+        crate::error::ErrorCode::RequestAlreadyResponded
+    );
+
+    let previous = &pending.tx_params;
+    require!(
+        tx_params.nonce == previous.nonce
+            && tx_params.chain_id == previous.chain_id
+            && tx_params.value == previous.value,
+        crate::error::ErrorCode::ReplacementMismatch
+    );
+    require!(
+        is_fee_bump(previous.max_fee_per_gas, tx_params.max_fee_per_gas)
+            && is_fee_bump(
+                previous.max_priority_fee_per_gas,
+                tx_params.max_priority_fee_per_gas
+            ),
+        crate::error::ErrorCode::FeeBumpTooLow
+    );
+    // Bumped fees still have to stay under the chain caps
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_token_transfer_allowed(&tx_params)?;
+
+    let rlp_encoded_tx = build_erc20_transfer(
+        pending.erc20_address,
+        pending.recipient_address,
+        pending.net_amount()?,
+        &tx_params,
+    );
+
+    // Signed under the same key version and path as the original
+    let computed_request_id = evm_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        pending.key_version,
+        &pending.path,
+    );
+
+    require!(
+        computed_request_id == replacement_request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    pending.replacement_request_ids.push(replacement_request_id);
+    // The original may still land, keep what is needed to record its signature
+    let superseded = std::mem::replace(&mut pending.tx_params, tx_params.clone());
+    pending.previous_tx_params.push(superseded);
+    pending.payload_hash = signing_hash(&rlp_encoded_tx);
+    pending.rlp_encoded_tx = rlp_encoded_tx.clone();
+    // The replacement can land as late as the original, restart the refund delay
+    pending.refundable_at_slot = Clock::get()?
+        .slot
+        .checked_add(ctx.accounts.config.withdrawal_refund_delay_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    let key_version = pending.key_version;
+    let path = pending.path.clone();
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
+            requester: ctx.accounts.requester.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
+        },
+        signer_seeds,
+        rlp_encoded_tx,
+        key_version,
+        path,
+        erc20_transfer_schemas()?,
+    )?;
+
+    msg!(
+        "ERC20 withdrawal {:?} fee bumped with request_id: {:?}",
+        request_id,
+        replacement_request_id
+    );
+
+    emit_cpi!(WithdrawalFeeBumped {
+        request_id,
+        replacement_request_id,
+        requester: ctx.accounts.pending_withdrawal.requester,
+        chain_id: tx_params.chain_id,
+        nonce: tx_params.nonce,
+        max_fee_per_gas: tx_params.max_fee_per_gas,
+        max_priority_fee_per_gas: tx_params.max_priority_fee_per_gas,
+    });
+
+    Ok(())
+}
+
+// EVM nodes only accept a replacement paying at least 10% more than the transaction it replaces
+fn is_fee_bump(previous: u128, replacement: u128) -> bool {
+    replacement > previous && replacement - previous >= previous / 10
+}
+
+// The fee authority's own deposits and withdrawals are free, so collected fees are not
+// charged again on their way out
+pub(crate) fn protocol_fee(
+    token: &TokenConfig,
+    fee_authority: &Pubkey,
+    requester: &Pubkey,
+    amount: u128,
+) -> Result<u128> {
+    if requester == fee_authority {
+        return Ok(0);
+    }
+    token.fee_for(amount)
+}
+
+/// Mints a claimed deposit as wrapped tokens into the requester's token account. Returns the
+/// dust below one wrapped unit, which is left for the balance
+fn mint_claimed_deposit<'info>(accounts: &ClaimErc20<'info>, net_amount: u128) -> Result<u128> {
+    let (Some(token_config), Some(wrapped_mint), Some(token_account), Some(token_program)) = (
+        accounts.token_config.as_ref(),
+        accounts.wrapped_mint.as_ref(),
+        accounts.wrapped_token_account.as_ref(),
+        accounts.token_program.as_ref(),
+    ) else {
+        return err!(crate::error::ErrorCode::WrappedAccountsMissing);
+    };
+    let pending = &accounts.pending_deposit;
+    require!(
+        token_account.mint == wrapped_mint.key() && token_account.owner == pending.requester,
+        crate::error::ErrorCode::InvalidWrappedTokenAccount
+    );
+
+    let (token_amount, dust) =
+        token_config.split_wrapped_amount(net_amount, wrapped_mint.decimals)?;
+    let (_, bump) = crate::pda::wrapped_mint(pending.chain_id, &pending.erc20_address);
+    mint_wrapped(
+        token_program,
+        &wrapped_mint.to_account_info(),
+        &token_account.to_account_info(),
+        pending.chain_id,
+        &pending.erc20_address,
+        bump,
+        token_amount,
+    )?;
+
+    Ok(dust)
+}
+
+/// Burns the wrapped tokens backing a withdrawal of `amount` from the owner's token account
+fn burn_withdrawn_wrapped<'info>(
+    accounts: &WithdrawErc20<'info>,
+    token: &TokenConfig,
+    amount: u128,
+) -> Result<()> {
+    let (Some(wrapped_mint), Some(token_account), Some(token_program)) = (
+        accounts.wrapped_mint.as_ref(),
+        accounts.wrapped_token_account.as_ref(),
+        accounts.token_program.as_ref(),
+    ) else {
+        return err!(crate::error::ErrorCode::WrappedAccountsMissing);
+    };
+    require!(
+        token_account.mint == wrapped_mint.key() && token_account.owner == accounts.owner.key(),
+        crate::error::ErrorCode::InvalidWrappedTokenAccount
+    );
+
+    burn_wrapped(
+        token_program,
+        &wrapped_mint.to_account_info(),
+        &token_account.to_account_info(),
+        &accounts.authority.to_account_info(),
+        token.to_wrapped_amount(amount, wrapped_mint.decimals)?,
+    )
+}
+
+fn credit_fee(fee_balance: &mut Option<Account<UserErc20Balance>>, fee: u128) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+    let fee_balance = fee_balance
+        .as_mut()
+        .ok_or(crate::error::ErrorCode::FeeBalanceMissing)?;
+    fee_balance.amount = fee_balance
+        .amount
+        .checked_add(fee)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    Ok(())
+}
+
+// ERC20 `transfer` outputs are decoded with its ABI and delivered to the callback as a Borsh bool
+pub(crate) fn erc20_transfer_schemas() -> Result<(Vec<u8>, Vec<u8>)> {
+    let functions = IERC20::abi::functions();
+    let transfer_func = functions
+        .get("transfer")
+        .and_then(|funcs| funcs.first())
+        .ok_or(crate::error::ErrorCode::FunctionNotFound)?;
+
+    let explorer_schema = serde_json::to_vec(&transfer_func.outputs)
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    let callback_schema = serde_json::to_vec(&serde_json::json!("bool"))
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    Ok((explorer_schema, callback_schema))
+}
+
+pub(crate) fn is_error_response(serialized_output: &[u8]) -> bool {
+    serialized_output.len() >= 4 && serialized_output[..4] == ERROR_PREFIX
+}
+
+/// Moves the relayer tip into the pending account, it sits there on top of the rent
+/// until a relayer delivers the response or the record is cancelled back to the payer.
+pub(crate) fn escrow_relayer_tip<'info>(
+    payer: &Signer<'info>,
+    pending: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    relayer_tip: u64,
+) -> Result<()> {
+    if relayer_tip == 0 {
+        return Ok(());
+    }
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: payer.to_account_info(),
+                to: pending.clone(),
+            },
+        ),
+        relayer_tip,
+    )
+}
+
+/// Pays the escrowed tip to the relayer, the remaining rent goes back on close.
+pub(crate) fn pay_relayer_tip<'info>(
+    pending: &AccountInfo<'info>,
+    relayer: &AccountInfo<'info>,
+    relayer_tip: u64,
+) -> Result<()> {
+    if relayer_tip == 0 {
+        return Ok(());
+    }
+
+    pending.sub_lamports(relayer_tip)?;
+    relayer.add_lamports(relayer_tip)?;
+
+    msg!("Paid relayer tip: {}", relayer_tip);
+
+    Ok(())
+}
+
+pub fn cancel_expired_deposit(
+    ctx: Context<CancelExpiredDeposit>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_deposit;
+    let slot = Clock::get()?.slot;
+
+    require!(
+        slot >= pending.expires_at_slot,
+        crate::error::ErrorCode::RequestNotExpired
+    );
+
+    ctx.accounts
+        .request_record
+        .settle(RequestStatus::Expired, None)?;
+
+    // No balance is credited, the account is closed back to the original payer
+    msg!(
+        "Expired ERC20 deposit cancelled with request_id: {:?}",
+        request_id
+    );
+
+    Ok(())
+}
+
+pub fn refund_expired_withdrawal(
+    ctx: Context<RefundExpiredWithdrawal>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+    let slot = Clock::get()?.slot;
+
+    // The signed transaction could still land on the EVM side, so a refund is only allowed
+    // once the refund delay (at least MIN_WITHDRAWAL_REFUND_DELAY_SLOTS) has passed
+    require!(
+        slot >= pending.refundable_at_slot,
+        crate::error::ErrorCode::RequestNotExpired
+    );
+
+    ctx.accounts
+        .request_record
+        .settle(RequestStatus::Expired, None)?;
+
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(pending.amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    ctx.accounts
+        .token_rate_limit
+        .release(pending.amount, pending.created_slot);
+    ctx.accounts
+        .user_rate_limit
+        .release(pending.amount, pending.created_slot);
+
+    msg!(
+        "Expired ERC20 withdrawal refunded with request_id: {:?}, balance: {}",
+        request_id,
+        balance.amount
+    );
+
+    emit_cpi!(WithdrawalRefunded {
+        request_id,
+        requester: pending.requester,
+        token: pending.erc20_address,
+        chain_id: pending.source_chain_id,
+        amount: pending.amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+// Verify a response against the MPC signer for the key version it was requested under
+pub(crate) fn verify_mpc_response(
+    mpc_signer: &MpcSigner,
+    message_hash: &[u8; 32],
+    signature: &chain_signatures::Signature,
+) -> Result<()> {
+    require!(
+        mpc_signer.is_trusted(Clock::get()?.unix_timestamp),
+        crate::error::ErrorCode::MpcSignerNotTrusted
+    );
+
+    verify_signature_from_address(message_hash, signature, &mpc_signer.address)
+}
+
 // Add this helper function to verify signature by recovering address
-fn verify_signature_from_address(
+pub(crate) fn verify_signature_from_address(
     message_hash: &[u8; 32],
     signature: &chain_signatures::Signature,
-    expected_address: &str,
+    expected_address: &[u8; 20],
 ) -> Result<()> {
     // Validate recovery ID
     require!(
@@ -426,64 +1048,10 @@ fn verify_signature_from_address(
     let pubkey_hash = keccak::hash(&pubkey_bytes);
     let address_bytes = &pubkey_hash.to_bytes()[12..]; // Last 20 bytes
 
-    // Convert to hex string for comparison
-    let recovered_address = format!("0x{}", hex::encode(address_bytes));
-
-    // Compare addresses (case-insensitive)
     require!(
-        recovered_address.to_lowercase() == expected_address.to_lowercase(),
+        address_bytes == expected_address.as_slice(),
         crate::error::ErrorCode::InvalidSignature
     );
 
     Ok(())
 }
-
-// Helper functions
-
-fn generate_sign_respond_request_id(
-    sender: &Pubkey,
-    transaction_data: &[u8],
-    slip44_chain_id: u32,
-    key_version: u32,
-    path: &str,
-    algo: &str,
-    dest: &str,
-    params: &str,
-) -> [u8; 32] {
-    use alloy_sol_types::SolValue;
-
-    msg!("=== generate_sign_respond_request_id ===");
-    msg!("Encoding with abi_encode_packed");
-
-    // Match TypeScript implementation using ABI encoding
-    let encoded = (
-        sender.to_string(),
-        transaction_data,
-        slip44_chain_id,
-        key_version,
-        path,
-        algo,
-        dest,
-        params,
-    )
-        .abi_encode_packed();
-
-    msg!("Encoded data length: {}", encoded.len());
-    msg!(
-        "Encoded data (first 32 bytes): {:?}",
-        &encoded[..32.min(encoded.len())]
-    );
-
-    let hash = keccak::hash(&encoded).to_bytes();
-    msg!("Resulting hash: {:?}", hash);
-
-    hash
-}
-
-fn hash_message(request_id: &[u8; 32], serialized_output: &[u8]) -> [u8; 32] {
-    let mut data = Vec::with_capacity(32 + serialized_output.len());
-    data.extend_from_slice(request_id);
-    data.extend_from_slice(serialized_output);
-
-    keccak::hash(&data).to_bytes()
-}
@@ -1,7 +1,31 @@
+pub mod allowance;
+pub mod balance_transfer;
+pub mod chain_config;
 pub mod erc20_vault;
+pub mod native_vault;
+pub mod nonce_tracker;
+pub mod preview_vault;
 pub mod process_vault;
+pub mod request_record;
 pub mod sign_vault;
+pub mod signed_withdrawal;
+pub mod token_registry;
+pub mod vault_config;
+pub mod withdrawal_queue;
+pub mod wrapped_token;
 
+pub use allowance::*;
+pub use balance_transfer::*;
+pub use chain_config::*;
 pub use erc20_vault::*;
+pub use native_vault::*;
+pub use nonce_tracker::*;
+pub use preview_vault::*;
 pub use process_vault::*;
+pub use request_record::*;
 pub use sign_vault::*;
+pub use signed_withdrawal::*;
+pub use token_registry::*;
+pub use vault_config::*;
+pub use withdrawal_queue::*;
+pub use wrapped_token::*;
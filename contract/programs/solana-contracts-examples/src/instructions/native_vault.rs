@@ -0,0 +1,525 @@
+use anchor_lang::prelude::*;
+use borsh::BorshDeserialize;
+
+use crate::constants::NATIVE_TOKEN_ADDRESS;
+use crate::events::{
+    DepositClaimed, DepositFailed, DepositRequested, WithdrawalCompleted, WithdrawalRefunded,
+    WithdrawalRequested,
+};
+use crate::evm::build_value_transfer;
+use crate::instructions::erc20_vault::{
+    escrow_relayer_tip, is_error_response, pay_relayer_tip, verify_mpc_response,
+    NonFunctionCallResult,
+};
+use crate::signing::{evm_request_id, hash_message, request_evm_signature, SignRequestAccounts};
+use crate::state::chain::ChainConfig;
+use crate::state::config::PausableAction;
+use crate::state::request::{RequestKind, RequestStatus};
+use crate::state::token::TokenConfig;
+use crate::state::vault::EvmTransactionParams;
+use crate::{
+    CancelExpiredNativeDeposit, ClaimNative, CompleteWithdrawNative, DepositNative,
+    RefundExpiredNativeWithdrawal, WithdrawNative,
+};
+
+pub fn deposit_native(
+    ctx: Context<DepositNative>,
+    request_id: [u8; 32],
+    requester: Pubkey,
+    amount: u128,
+    tx_params: EvmTransactionParams,
+    relayer_tip: u64,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Deposit)?;
+
+    TokenConfig::load(&ctx.accounts.token_config)?.require_deposit_allowed(amount)?;
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_gas_allowed(&tx_params)?;
+
+    require!(
+        tx_params.value == amount,
+        crate::error::ErrorCode::InvalidTransactionValue
+    );
+
+    let path = requester.to_string();
+    let key_version = ctx.accounts.config.current_key_version;
+
+    // Build a plain value transfer sweeping funds to the configured recipient
+    let rlp_encoded_tx = build_value_transfer(ctx.accounts.config.sweep_recipient, &tx_params);
+
+    let computed_request_id = evm_request_id(
+        &ctx.accounts.requester_pda.key(),
+        &rlp_encoded_tx,
+        key_version,
+        &path,
+    );
+
+    msg!("Computed request ID: {:?}", computed_request_id);
+    msg!("Provided request ID: {:?}", request_id);
+
+    require!(
+        computed_request_id == request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    // Store pending deposit info
+    let pending = &mut ctx.accounts.pending_deposit;
+    pending.requester = requester;
+    pending.amount = amount;
+    pending.chain_id = tx_params.chain_id;
+    pending.path = path.clone();
+    pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.payer = ctx.accounts.payer.key();
+    pending.relayer_tip = relayer_tip;
+    pending.created_slot = Clock::get()?.slot;
+    pending.expires_at_slot = pending
+        .created_slot
+        .checked_add(ctx.accounts.config.deposit_timeout_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    ctx.accounts.request_record.open(
+        request_id,
+        requester,
+        ctx.accounts.payer.key(),
+        RequestKind::NativeDeposit,
+        NATIVE_TOKEN_ADDRESS,
+        tx_params.chain_id,
+        amount,
+        pending.created_slot,
+    );
+
+    escrow_relayer_tip(
+        &ctx.accounts.payer,
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.system_program,
+        relayer_tip,
+    )?;
+
+    let requester_key_bytes = requester.to_bytes();
+    let requester_bump = ctx.bumps.requester_pda;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        requester_key_bytes.as_ref(),
+        &[requester_bump],
+    ]];
+
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
+            requester: ctx.accounts.requester_pda.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
+        },
+        signer_seeds,
+        rlp_encoded_tx,
+        key_version,
+        path,
+        value_transfer_schemas()?,
+    )?;
+
+    msg!("Native deposit initiated with request_id: {:?}", request_id);
+
+    emit_cpi!(DepositRequested {
+        request_id,
+        requester,
+        token: NATIVE_TOKEN_ADDRESS,
+        chain_id: ctx.accounts.pending_deposit.chain_id,
+        amount,
+        // Protocol fees are only charged on ERC20s
+        fee: 0,
+        net_amount: amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+pub fn claim_native(
+    ctx: Context<ClaimNative>,
+    request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    ctx.accounts.config.require_active(PausableAction::Claim)?;
+
+    let pending = &ctx.accounts.pending_deposit;
+
+    let message_hash = hash_message(&request_id, &serialized_output);
+    verify_mpc_response(&ctx.accounts.mpc_signer, &message_hash, &signature)?;
+
+    msg!("Signature verified successfully");
+
+    let success = transfer_succeeded(&serialized_output)?;
+    if success {
+        let balance = &mut ctx.accounts.user_balance;
+        balance.amount = balance
+            .amount
+            .checked_add(pending.amount)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+
+        msg!(
+            "Native deposit claimed successfully. New balance: {}",
+            balance.amount
+        );
+
+        emit_cpi!(DepositClaimed {
+            request_id,
+            requester: pending.requester,
+            token: NATIVE_TOKEN_ADDRESS,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            fee: 0,
+            net_amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    } else {
+        msg!("Native deposit failed, nothing credited");
+
+        emit_cpi!(DepositFailed {
+            request_id,
+            requester: pending.requester,
+            token: NATIVE_TOKEN_ADDRESS,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    }
+
+    let status = if success {
+        RequestStatus::Claimed
+    } else {
+        RequestStatus::Failed
+    };
+    ctx.accounts
+        .request_record
+        .settle(status, Some(&serialized_output))?;
+
+    pay_relayer_tip(
+        &ctx.accounts.pending_deposit.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        pending.relayer_tip,
+    )?;
+
+    Ok(())
+}
+
+pub fn withdraw_native(
+    ctx: Context<WithdrawNative>,
+    request_id: [u8; 32],
+    amount: u128,
+    recipient_address: [u8; 20],
+    tx_params: EvmTransactionParams,
+    relayer_tip: u64,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    let token = TokenConfig::load(&ctx.accounts.token_config)?;
+    token.require_withdrawal_allowed(amount)?;
+    token.consume_withdrawal_limits(
+        &mut ctx.accounts.token_rate_limit,
+        &mut ctx.accounts.user_rate_limit,
+        amount,
+    )?;
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_gas_allowed(&tx_params)?;
+
+    require!(
+        tx_params.value == amount,
+        crate::error::ErrorCode::InvalidTransactionValue
+    );
+
+    let authority = ctx.accounts.authority.key();
+    let path = ctx.accounts.config.withdrawal_root_path.clone();
+    let key_version = ctx.accounts.config.current_key_version;
+
+    let balance = &mut ctx.accounts.user_balance;
+    require!(
+        balance.amount >= amount,
+        crate::error::ErrorCode::InsufficientBalance
+    );
+
+    // Optimistically decrement the balance
+    balance.amount = balance
+        .amount
+        .checked_sub(amount)
+        .ok_or(crate::error::ErrorCode::Underflow)?;
+
+    msg!("Optimistically decremented native balance by {}", amount);
+
+    // Withdrawals share one signing address, nonces are handed out in order
+    ctx.accounts.nonce_tracker.consume(tx_params.nonce)?;
+
+    // Value transfer FROM the withdrawal address of the current key
+    let rlp_encoded_tx = build_value_transfer(recipient_address, &tx_params);
+
+    let computed_request_id = evm_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        key_version,
+        &path,
+    );
+
+    msg!("Computed request ID: {:?}", computed_request_id);
+    msg!("Provided request ID: {:?}", request_id);
+
+    require!(
+        computed_request_id == request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    // Store pending withdrawal info
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    pending.requester = authority;
+    pending.amount = amount;
+    pending.recipient_address = recipient_address;
+    pending.chain_id = tx_params.chain_id;
+    pending.path = path.clone();
+    pending.request_id = request_id;
+    pending.key_version = key_version;
+    pending.payer = authority;
+    pending.relayer_tip = relayer_tip;
+    pending.created_slot = Clock::get()?.slot;
+    pending.refundable_at_slot = pending
+        .created_slot
+        .checked_add(ctx.accounts.config.withdrawal_refund_delay_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+    pending.rlp_encoded_tx = rlp_encoded_tx.clone();
+    pending.evm_tx_hash = [0u8; 32];
+    pending.signer_address = ctx.accounts.current_signer.withdrawal_address;
+
+    ctx.accounts.request_record.open(
+        request_id,
+        authority,
+        authority,
+        RequestKind::NativeWithdrawal,
+        NATIVE_TOKEN_ADDRESS,
+        tx_params.chain_id,
+        amount,
+        pending.created_slot,
+    );
+
+    escrow_relayer_tip(
+        &ctx.accounts.authority,
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.system_program,
+        relayer_tip,
+    )?;
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
+            requester: ctx.accounts.requester.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
+        },
+        signer_seeds,
+        rlp_encoded_tx,
+        key_version,
+        path,
+        value_transfer_schemas()?,
+    )?;
+
+    msg!(
+        "Native withdrawal initiated with request_id: {:?}",
+        request_id
+    );
+
+    emit_cpi!(WithdrawalRequested {
+        request_id,
+        requester: authority,
+        token: NATIVE_TOKEN_ADDRESS,
+        chain_id: ctx.accounts.pending_withdrawal.chain_id,
+        amount,
+        fee: 0,
+        net_amount: amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+pub fn complete_withdraw_native(
+    ctx: Context<CompleteWithdrawNative>,
+    request_id: [u8; 32],
+    serialized_output: Vec<u8>,
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+
+    let message_hash = hash_message(&request_id, &serialized_output);
+    verify_mpc_response(&ctx.accounts.mpc_signer, &message_hash, &signature)?;
+
+    msg!("Signature verified successfully");
+
+    let should_refund = !transfer_succeeded(&serialized_output)?;
+
+    if !should_refund {
+        ctx.accounts
+            .config
+            .require_active(PausableAction::Completion)?;
+    }
+
+    if should_refund {
+        let balance = &mut ctx.accounts.user_balance;
+        balance.amount = balance
+            .amount
+            .checked_add(pending.amount)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+
+        msg!("Native balance refunded: {}", pending.amount);
+
+        ctx.accounts
+            .token_rate_limit
+            .release(pending.amount, pending.created_slot);
+        ctx.accounts
+            .user_rate_limit
+            .release(pending.amount, pending.created_slot);
+
+        emit_cpi!(WithdrawalRefunded {
+            request_id,
+            requester: pending.requester,
+            token: NATIVE_TOKEN_ADDRESS,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    } else {
+        emit_cpi!(WithdrawalCompleted {
+            request_id,
+            requester: pending.requester,
+            token: NATIVE_TOKEN_ADDRESS,
+            chain_id: pending.chain_id,
+            amount: pending.amount,
+            fee: 0,
+            net_amount: pending.amount,
+            balance: ctx.accounts.user_balance.amount,
+        });
+    }
+
+    let status = if should_refund {
+        RequestStatus::Refunded
+    } else {
+        RequestStatus::Claimed
+    };
+    ctx.accounts
+        .request_record
+        .settle(status, Some(&serialized_output))?;
+
+    pay_relayer_tip(
+        &ctx.accounts.pending_withdrawal.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        pending.relayer_tip,
+    )?;
+
+    msg!("Native withdrawal process completed");
+
+    Ok(())
+}
+
+pub fn cancel_expired_native_deposit(
+    ctx: Context<CancelExpiredNativeDeposit>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_deposit;
+    let slot = Clock::get()?.slot;
+
+    require!(
+        slot >= pending.expires_at_slot,
+        crate::error::ErrorCode::RequestNotExpired
+    );
+
+    ctx.accounts
+        .request_record
+        .settle(RequestStatus::Expired, None)?;
+
+    // No balance is credited, the account is closed back to the original payer
+    msg!(
+        "Expired native deposit cancelled with request_id: {:?}",
+        request_id
+    );
+
+    Ok(())
+}
+
+pub fn refund_expired_native_withdrawal(
+    ctx: Context<RefundExpiredNativeWithdrawal>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+    let slot = Clock::get()?.slot;
+
+    require!(
+        slot >= pending.refundable_at_slot,
+        crate::error::ErrorCode::RequestNotExpired
+    );
+
+    ctx.accounts
+        .request_record
+        .settle(RequestStatus::Expired, None)?;
+
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(pending.amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    ctx.accounts
+        .token_rate_limit
+        .release(pending.amount, pending.created_slot);
+    ctx.accounts
+        .user_rate_limit
+        .release(pending.amount, pending.created_slot);
+
+    msg!(
+        "Expired Native withdrawal refunded with request_id: {:?}, native balance: {}",
+        request_id,
+        balance.amount
+    );
+
+    emit_cpi!(WithdrawalRefunded {
+        request_id,
+        requester: pending.requester,
+        token: NATIVE_TOKEN_ADDRESS,
+        chain_id: pending.chain_id,
+        amount: pending.amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+// Value transfers have no ABI outputs, the MPC network answers with a Borsh `NonFunctionCallResult`
+fn value_transfer_schemas() -> Result<(Vec<u8>, Vec<u8>)> {
+    let explorer_schema = serde_json::to_vec(&serde_json::json!([]))
+        .map_err(|_| crate::error::ErrorCode::SerializationError)?;
+
+    let callback_schema = crate::schema_helper::get_schema_json_bytes::<NonFunctionCallResult>()?;
+
+    Ok((explorer_schema, callback_schema))
+}
+
+fn transfer_succeeded(serialized_output: &[u8]) -> Result<bool> {
+    if is_error_response(serialized_output) {
+        msg!("Detected error response (magic prefix)");
+        return Ok(false);
+    }
+
+    let result = NonFunctionCallResult::try_from_slice(serialized_output)
+        .map_err(|_| crate::error::ErrorCode::InvalidOutput)?;
+
+    msg!("Value transfer result: {}", result.message);
+
+    Ok(true)
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::nonce::NonceTracker;
+use crate::SyncNonceTracker;
+
+/// Creates the tracker or resyncs it once it drifted from the nonce observed on the EVM side,
+/// e.g. after a signed withdrawal was never broadcast
+pub fn sync_nonce_tracker(
+    ctx: Context<SyncNonceTracker>,
+    chain_id: u64,
+    path: String,
+    key_version: u32,
+    next_nonce: u64,
+) -> Result<()> {
+    require!(
+        path.len() <= NonceTracker::MAX_PATH_LEN,
+        crate::error::ErrorCode::PathTooLong
+    );
+
+    let tracker = &mut ctx.accounts.nonce_tracker;
+    let previous_nonce = tracker.next_nonce;
+
+    tracker.chain_id = chain_id;
+    tracker.path = path;
+    tracker.key_version = key_version;
+    tracker.next_nonce = next_nonce;
+
+    msg!(
+        "Nonce tracker for {} (key version {}) on chain {} synced from {} to {}",
+        tracker.path,
+        key_version,
+        chain_id,
+        previous_nonce,
+        next_nonce
+    );
+
+    Ok(())
+}
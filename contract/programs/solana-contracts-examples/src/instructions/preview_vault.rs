@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::evm::{build_erc20_transfer, signing_hash};
+use crate::instructions::erc20_vault::protocol_fee;
+use crate::pda;
+use crate::signing::evm_request_id;
+use crate::state::token::TokenConfig;
+use crate::state::vault::{EvmTransactionParams, SignRequestPreview};
+use crate::{PreviewVault, PreviewWithdrawErc20};
+
+pub fn preview_deposit_erc20(
+    ctx: Context<PreviewVault>,
+    requester: Pubkey,
+    erc20_address: [u8; 20],
+    amount: u128,
+    tx_params: EvmTransactionParams,
+) -> Result<SignRequestPreview> {
+    let config = &ctx.accounts.config;
+    let (requester_pda, _) = pda::vault_authority(&requester);
+
+    let rlp_encoded_tx =
+        build_erc20_transfer(erc20_address, config.sweep_recipient, amount, &tx_params);
+
+    Ok(preview(
+        &requester_pda,
+        rlp_encoded_tx,
+        config.current_key_version,
+        requester.to_string(),
+    ))
+}
+
+pub fn preview_withdraw_erc20(
+    ctx: Context<PreviewWithdrawErc20>,
+    requester: Pubkey,
+    erc20_address: [u8; 20],
+    amount: u128,
+    recipient_address: [u8; 20],
+    tx_params: EvmTransactionParams,
+) -> Result<SignRequestPreview> {
+    let config = &ctx.accounts.config;
+    let (global_vault_authority, _) = pda::global_vault_authority();
+
+    // The transfer sends the amount net of the requester's protocol fee
+    let token = TokenConfig::load(&ctx.accounts.token_config)?;
+    let fee = protocol_fee(&token, &config.fee_authority, &requester, amount)?;
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(crate::error::ErrorCode::AmountBelowFee)?;
+
+    let rlp_encoded_tx =
+        build_erc20_transfer(erc20_address, recipient_address, net_amount, &tx_params);
+
+    Ok(preview(
+        &global_vault_authority,
+        rlp_encoded_tx,
+        config.current_key_version,
+        config.withdrawal_root_path.clone(),
+    ))
+}
+
+fn preview(
+    sender: &Pubkey,
+    rlp_encoded_tx: Vec<u8>,
+    key_version: u32,
+    path: String,
+) -> SignRequestPreview {
+    SignRequestPreview {
+        signing_hash: signing_hash(&rlp_encoded_tx),
+        request_id: evm_request_id(sender, &rlp_encoded_tx, key_version, &path),
+        rlp_encoded_tx,
+        path,
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::events::RequestRecordCompressed;
+use crate::state::request::{RequestRecord, RequestStatus, RequestTombstone};
+use crate::CompressRequestRecord;
+
+pub fn compress_request_record(
+    ctx: Context<CompressRequestRecord>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let info = ctx.accounts.request_record.to_account_info();
+    // Fails on tombstones, a record is only compressed once
+    let record = RequestRecord::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == record.requester || signer == ctx.accounts.config.authority,
+        crate::error::ErrorCode::Unauthorized
+    );
+    require!(
+        ctx.accounts.rent_payer.key() == record.payer,
+        crate::error::ErrorCode::Unauthorized
+    );
+    require!(
+        record.status != RequestStatus::Pending,
+        crate::error::ErrorCode::RequestStillPending
+    );
+
+    // The record lives on in the event log. The account shrinks to a tombstone instead of
+    // closing, its address is the replay guard for the request id
+    emit_cpi!(RequestRecordCompressed {
+        request_id,
+        requester: record.requester,
+        kind: record.kind,
+        token: record.token,
+        chain_id: record.chain_id,
+        amount: record.amount,
+        status: record.status,
+        output_hash: record.output_hash,
+        created_slot: record.created_slot,
+        completed_slot: record.completed_slot,
+    });
+
+    let tombstone = RequestTombstone {
+        request_id,
+        status: record.status,
+    };
+    info.resize(RequestTombstone::space())?;
+    tombstone.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    // Rent freed by shrinking goes back to the payer
+    let excess = info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(RequestTombstone::space()));
+    **info.try_borrow_mut_lamports()? -= excess;
+    **ctx.accounts.rent_payer.try_borrow_mut_lamports()? += excess;
+
+    msg!("Request record compressed for request_id: {:?}", request_id);
+
+    Ok(())
+}
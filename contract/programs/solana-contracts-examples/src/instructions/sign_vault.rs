@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::cpi::SignAccounts;
 use crate::instructions::process_vault::process_vault_transaction;
 use crate::state::chain_signatures::{SignatureRequest, SigningParams};
+use crate::state::config::PausableAction;
 use crate::state::vault::*;
 
 pub fn sign_deposit_transaction(
@@ -10,6 +11,10 @@ pub fn sign_deposit_transaction(
     tx: VaultTransaction,
     signing_params: SigningParams,
 ) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Deposit)?;
+
     let tx_hash = process_vault_transaction::<DepositOp>(tx)?;
     request_signature(ctx, tx_hash, &signing_params)
 }
@@ -19,6 +24,10 @@ pub fn sign_withdraw_transaction(
     tx: VaultTransaction,
     signing_params: SigningParams,
 ) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
     let tx_hash = process_vault_transaction::<WithdrawOp>(tx)?;
     request_signature(ctx, tx_hash, &signing_params)
 }
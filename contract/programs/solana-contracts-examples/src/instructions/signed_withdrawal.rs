@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::NATIVE_TOKEN_ADDRESS;
+use crate::events::WithdrawalSigned;
+use crate::evm::{
+    assemble_signed_transaction, build_erc20_transfer, signing_hash, SignedEvmTransaction,
+};
+use crate::instructions::erc20_vault::verify_signature_from_address;
+use crate::{RecordSignedErc20Withdrawal, RecordSignedNativeWithdrawal};
+
+/// Records the MPC signature of one of the withdrawal's transactions. `signed_request_id` picks
+/// the original or a fee bump, whichever lands each can be recorded once.
+pub fn record_signed_erc20_withdrawal(
+    ctx: Context<RecordSignedErc20Withdrawal>,
+    request_id: [u8; 32],
+    signed_request_id: [u8; 32],
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    let bit = pending.response_bit(&signed_request_id)?;
+    require!(
+        pending.recorded & bit == 0,
+        crate::error::ErrorCode::SignedTransactionAlreadyRecorded
+    );
+
+    let rlp_encoded_tx = build_erc20_transfer(
+        pending.erc20_address,
+        pending.recipient_address,
+        pending.net_amount()?,
+        pending.tx_params_of(&signed_request_id)?,
+    );
+    let signed = sign_withdrawal(&rlp_encoded_tx, &pending.signer_address, &signature)?;
+    pending.recorded |= bit;
+    pending.evm_tx_hash = signed.tx_hash;
+
+    msg!(
+        "ERC20 withdrawal signed with request_id: {:?}, tx hash: {:?}",
+        signed_request_id,
+        signed.tx_hash
+    );
+
+    emit_cpi!(WithdrawalSigned {
+        request_id,
+        requester: ctx.accounts.pending_withdrawal.requester,
+        token: ctx.accounts.pending_withdrawal.erc20_address,
+        chain_id: ctx.accounts.pending_withdrawal.chain_id,
+        tx_hash: signed.tx_hash,
+        raw_transaction: signed.raw_transaction,
+    });
+
+    Ok(())
+}
+
+pub fn record_signed_native_withdrawal(
+    ctx: Context<RecordSignedNativeWithdrawal>,
+    request_id: [u8; 32],
+    signature: chain_signatures::Signature,
+) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    require!(
+        pending.evm_tx_hash == [0u8; 32],
+        crate::error::ErrorCode::SignedTransactionAlreadyRecorded
+    );
+
+    let signed = sign_withdrawal(&pending.rlp_encoded_tx, &pending.signer_address, &signature)?;
+    pending.evm_tx_hash = signed.tx_hash;
+
+    msg!(
+        "Native withdrawal signed with request_id: {:?}, tx hash: {:?}",
+        request_id,
+        signed.tx_hash
+    );
+
+    emit_cpi!(WithdrawalSigned {
+        request_id,
+        requester: ctx.accounts.pending_withdrawal.requester,
+        token: NATIVE_TOKEN_ADDRESS,
+        chain_id: ctx.accounts.pending_withdrawal.chain_id,
+        tx_hash: signed.tx_hash,
+        raw_transaction: signed.raw_transaction,
+    });
+
+    Ok(())
+}
+
+// The signature must come from the address the transfer is sent from, as pinned in the
+// pending withdrawal
+fn sign_withdrawal(
+    rlp_encoded_tx: &[u8],
+    signer_address: &[u8; 20],
+    signature: &chain_signatures::Signature,
+) -> Result<SignedEvmTransaction> {
+    verify_signature_from_address(&signing_hash(rlp_encoded_tx), signature, signer_address)?;
+
+    assemble_signed_transaction(rlp_encoded_tx, signature)
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::token::TokenConfigParams;
+use crate::{RegisterToken, UpdateToken};
+
+pub fn register_token(
+    ctx: Context<RegisterToken>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    params: TokenConfigParams,
+) -> Result<()> {
+    let token = &mut ctx.accounts.token_config;
+    token.chain_id = chain_id;
+    token.erc20_address = erc20_address;
+    token.apply(params)?;
+
+    msg!(
+        "Registered token {} (0x{}) on chain {}",
+        token.symbol,
+        hex::encode(erc20_address),
+        chain_id
+    );
+
+    Ok(())
+}
+
+pub fn update_token(
+    ctx: Context<UpdateToken>,
+    _chain_id: u64,
+    _erc20_address: [u8; 20],
+    params: TokenConfigParams,
+) -> Result<()> {
+    let token = &mut ctx.accounts.token_config;
+    token.apply(params)?;
+
+    msg!(
+        "Updated token {} on chain {}, enabled: {}",
+        token.symbol,
+        token.chain_id,
+        token.enabled
+    );
+
+    Ok(())
+}
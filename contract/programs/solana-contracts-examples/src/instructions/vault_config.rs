@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::state::config::{PauseFlags, VaultConfigParams};
+use crate::{
+    InitializeConfig, RegisterMpcSigner, RevokeMpcSigner, RotateMpcKey, SetPauseFlags, UpdateConfig,
+};
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, params: VaultConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.apply(params)?;
+
+    msg!("Vault config initialized by {}", config.authority);
+
+    Ok(())
+}
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    params: VaultConfigParams,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.apply(params)?;
+
+    if let Some(new_authority) = new_authority {
+        config.authority = new_authority;
+        msg!("Vault config authority transferred to {}", new_authority);
+    }
+
+    msg!("Vault config updated");
+
+    Ok(())
+}
+
+pub fn register_mpc_signer(
+    ctx: Context<RegisterMpcSigner>,
+    key_version: u32,
+    address: [u8; 20],
+    withdrawal_address: [u8; 20],
+) -> Result<()> {
+    let signer = &mut ctx.accounts.mpc_signer;
+    signer.key_version = key_version;
+    signer.address = address;
+    signer.withdrawal_address = withdrawal_address;
+    signer.valid_until = i64::MAX;
+
+    msg!("Registered MPC signer for key version {}", key_version);
+
+    Ok(())
+}
+
+pub fn rotate_mpc_key(ctx: Context<RotateMpcKey>, new_key_version: u32) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        new_key_version != config.current_key_version,
+        crate::error::ErrorCode::InvalidKeyVersion
+    );
+    require!(
+        ctx.accounts.new_signer.is_trusted(now),
+        crate::error::ErrorCode::MpcSignerNotTrusted
+    );
+
+    // Keep accepting the previous key until in-flight requests have had time to settle
+    let previous = &mut ctx.accounts.current_signer;
+    previous.valid_until = now
+        .checked_add(config.key_rotation_grace_period)
+        .ok_or(crate::error::ErrorCode::Overflow)?
+        .min(previous.valid_until);
+
+    msg!(
+        "Rotated MPC key version {} -> {}, previous trusted until {}",
+        config.current_key_version,
+        new_key_version,
+        previous.valid_until
+    );
+
+    ctx.accounts.new_signer.valid_until = i64::MAX;
+    config.current_key_version = new_key_version;
+
+    Ok(())
+}
+
+pub fn revoke_mpc_signer(ctx: Context<RevokeMpcSigner>, key_version: u32) -> Result<()> {
+    require!(
+        key_version != ctx.accounts.config.current_key_version,
+        crate::error::ErrorCode::InvalidKeyVersion
+    );
+
+    let signer = &mut ctx.accounts.mpc_signer;
+    signer.valid_until = Clock::get()?.unix_timestamp;
+
+    msg!("Revoked MPC signer for key version {}", key_version);
+
+    Ok(())
+}
+
+pub fn set_pause_flags(ctx: Context<SetPauseFlags>, flags: PauseFlags) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let config = &mut ctx.accounts.config;
+
+    // The guardian can only tighten the breakers, lifting a pause is reserved to the authority
+    let is_authority = signer == config.authority;
+    let is_guardian = signer == config.guardian;
+    require!(
+        is_authority || (is_guardian && !config.pause.is_lifted_by(&flags)),
+        crate::error::ErrorCode::Unauthorized
+    );
+
+    config.pause = flags;
+
+    msg!("Vault pause flags set to {:?} by {}", flags, signer);
+
+    Ok(())
+}
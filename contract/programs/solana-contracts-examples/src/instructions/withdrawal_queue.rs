@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use crate::events::{QueuedWithdrawalExecuted, WithdrawalCancelled};
+use crate::evm::{build_erc20_transfer, signing_hash};
+use crate::instructions::erc20_vault::erc20_transfer_schemas;
+use crate::signing::{evm_request_id, request_evm_signature, SignRequestAccounts};
+use crate::state::chain::ChainConfig;
+use crate::state::config::PausableAction;
+use crate::state::request::RequestStatus;
+use crate::state::vault::EvmTransactionParams;
+use crate::{CancelQueuedWithdrawal, ExecuteQueuedWithdrawal};
+
+/// Sends a queued withdrawal for signing once its delay has passed, callable by anyone. The
+/// transfer takes the next nonce and the current key, so responses arrive for
+/// `signed_request_id` rather than `request_id`.
+pub fn execute_queued_withdrawal(
+    ctx: Context<ExecuteQueuedWithdrawal>,
+    request_id: [u8; 32],
+    signed_request_id: [u8; 32],
+    tx_params: EvmTransactionParams,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .require_active(PausableAction::Withdrawal)?;
+
+    let pending = &mut ctx.accounts.pending_withdrawal;
+    require!(
+        pending.is_queued(),
+        crate::error::ErrorCode::WithdrawalNotQueued
+    );
+
+    let slot = Clock::get()?.slot;
+    require!(
+        slot >= pending.executable_at_slot,
+        crate::error::ErrorCode::WithdrawalDelayNotElapsed
+    );
+
+    require!(
+        tx_params.chain_id == pending.chain_id,
+        crate::error::ErrorCode::ChainMismatch
+    );
+    ChainConfig::load(&ctx.accounts.chain_config)?.require_token_transfer_allowed(&tx_params)?;
+    ctx.accounts.nonce_tracker.consume(tx_params.nonce)?;
+
+    let path = ctx.accounts.config.withdrawal_root_path.clone();
+    let key_version = ctx.accounts.config.current_key_version;
+
+    let rlp_encoded_tx = build_erc20_transfer(
+        pending.erc20_address,
+        pending.recipient_address,
+        pending.net_amount()?,
+        &tx_params,
+    );
+
+    let computed_request_id = evm_request_id(
+        &ctx.accounts.requester.key(),
+        &rlp_encoded_tx,
+        key_version,
+        &path,
+    );
+
+    require!(
+        computed_request_id == signed_request_id,
+        crate::error::ErrorCode::InvalidRequestId
+    );
+
+    pending.executable_at_slot = 0;
+    pending.signed_request_id = signed_request_id;
+    pending.path = path.clone();
+    pending.key_version = key_version;
+    pending.signer_address = ctx.accounts.current_signer.withdrawal_address;
+    pending.tx_params = tx_params.clone();
+    pending.payload_hash = signing_hash(&rlp_encoded_tx);
+    pending.rlp_encoded_tx = rlp_encoded_tx.clone();
+    // The refund delay covers the signed transaction, it starts now
+    pending.refundable_at_slot = slot
+        .checked_add(ctx.accounts.config.withdrawal_refund_delay_slots)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    let requester_bump = ctx.bumps.requester;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"global_vault_authority", &[requester_bump]]];
+
+    request_evm_signature(
+        SignRequestAccounts {
+            chain_signatures_program: &ctx.accounts.chain_signatures_program,
+            chain_signatures_state: &ctx.accounts.chain_signatures_state,
+            chain_signatures_event_authority: &ctx.accounts.chain_signatures_event_authority,
+            requester: ctx.accounts.requester.to_account_info(),
+            fee_payer: ctx.accounts.fee_payer.as_ref(),
+            system_program: &ctx.accounts.system_program,
+            instructions: ctx.accounts.instructions.as_ref(),
+        },
+        signer_seeds,
+        rlp_encoded_tx,
+        key_version,
+        path,
+        erc20_transfer_schemas()?,
+    )?;
+
+    msg!(
+        "Queued ERC20 withdrawal {:?} executed with request_id: {:?}",
+        request_id,
+        signed_request_id
+    );
+
+    emit_cpi!(QueuedWithdrawalExecuted {
+        request_id,
+        signed_request_id,
+        requester: ctx.accounts.pending_withdrawal.requester,
+        chain_id: tx_params.chain_id,
+        nonce: tx_params.nonce,
+    });
+
+    Ok(())
+}
+
+/// Guardian veto of a queued withdrawal, nothing was signed yet so the balance is restored
+/// right away
+pub fn cancel_queued_withdrawal(
+    ctx: Context<CancelQueuedWithdrawal>,
+    request_id: [u8; 32],
+) -> Result<()> {
+    let pending = &ctx.accounts.pending_withdrawal;
+    require!(
+        pending.is_queued(),
+        crate::error::ErrorCode::WithdrawalNotQueued
+    );
+
+    ctx.accounts
+        .request_record
+        .settle(RequestStatus::Cancelled, None)?;
+
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(pending.amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    ctx.accounts
+        .token_rate_limit
+        .release(pending.amount, pending.created_slot);
+    ctx.accounts
+        .user_rate_limit
+        .release(pending.amount, pending.created_slot);
+
+    msg!(
+        "Queued ERC20 withdrawal cancelled with request_id: {:?}, balance: {}",
+        request_id,
+        balance.amount
+    );
+
+    emit_cpi!(WithdrawalCancelled {
+        request_id,
+        requester: pending.requester,
+        token: pending.erc20_address,
+        chain_id: pending.source_chain_id,
+        amount: pending.amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
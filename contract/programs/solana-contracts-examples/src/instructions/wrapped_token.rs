@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    burn, mint_to, token_metadata_initialize, Burn, MintTo, TokenMetadataInitialize,
+};
+
+use crate::events::{Erc20BalanceUnwrapped, Erc20BalanceWrapped};
+use crate::state::config::PausableAction;
+use crate::{CreateWrappedMint, UnwrapErc20Balance, WrapErc20Balance};
+
+/// Creates the Token-2022 mint representing a registered token, named after its registry entry.
+/// The mint PDA is its own mint authority, tokens only come from wrapping a balance or from
+/// a deposit claimed in wrapped mode, and withdrawals can burn them directly.
+/// Tokens with more than `MAX_WRAPPED_DECIMALS` decimals are wrapped at that precision.
+pub fn create_wrapped_mint(
+    ctx: Context<CreateWrappedMint>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+) -> Result<()> {
+    let token = &ctx.accounts.token_config;
+    let (name, symbol, uri) = (token.name.clone(), token.symbol.clone(), token.uri.clone());
+
+    // Token metadata is appended to the mint on initialization, fund the extra rent first
+    let mint_info = ctx.accounts.wrapped_mint.to_account_info();
+    let required_lamports = Rent::get()?
+        .minimum_balance(mint_info.data_len() + token_metadata_space(&name, &symbol, &uri));
+    let missing_lamports = required_lamports.saturating_sub(mint_info.lamports());
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    mint_wrapped(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wrapped_mint.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        chain_id,
+        &erc20_address,
+        ctx.bumps.wrapped_mint,
+        token_amount,
+    )?;
+
+    msg!(
+        "Wrapped {} into {}",
+        amount,
+        ctx.accounts.wrapped_mint.key()
+    );
+
+    emit_cpi!(Erc20BalanceWrapped {
+        owner: ctx.accounts.authority.key(),
+        token: erc20_address,
+        chain_id,
+        amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+/// Burns wrapped tokens back into the caller's balance, from where they can be withdrawn.
+/// Always allowed, it only moves value back under the vault's control. `amount` is in the
+/// token's EVM units, as for wrapping.
+pub fn unwrap_erc20_balance(
+    ctx: Context<UnwrapErc20Balance>,
+    chain_id: u64,
+    erc20_address: [u8; 20],
+    amount: u128,
+) -> Result<()> {
+    require!(amount > 0, crate::error::ErrorCode::InvalidAmount);
+    let token_amount = ctx
+        .accounts
+        .token_config
+        .to_wrapped_amount(amount, ctx.accounts.wrapped_mint.decimals)?;
+
+    burn_wrapped(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wrapped_mint.to_account_info(),
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        token_amount,
+    )?;
+
+    let balance = &mut ctx.accounts.user_balance;
+    balance.amount = balance
+        .amount
+        .checked_add(amount)
+        .ok_or(crate::error::ErrorCode::Overflow)?;
+
+    msg!(
+        "Unwrapped {} from {}",
+        amount,
+        ctx.accounts.wrapped_mint.key()
+    );
+
+    emit_cpi!(Erc20BalanceUnwrapped {
+        owner: ctx.accounts.authority.key(),
+        token: erc20_address,
+        chain_id,
+        amount,
+        balance: ctx.accounts.user_balance.amount,
+    });
+
+    Ok(())
+}
+
+/// Mints wrapped tokens, signed by the mint PDA of `chain_id` and `erc20_address`
+pub(crate) fn mint_wrapped<'info>(
+    token_program: &Program<'info, Token2022>,
+    wrapped_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    chain_id: u64,
+    erc20_address: &[u8; 20],
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let chain_id_bytes = chain_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"wrapped_mint",
+        chain_id_bytes.as_ref(),
+        erc20_address.as_ref(),
+        &[bump],
+    ]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: wrapped_mint.clone(),
+                to: to.clone(),
+                authority: wrapped_mint.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Burns wrapped tokens, `authority` is the holder or a delegate they approved on the account
+pub(crate) fn burn_wrapped<'info>(
+    token_program: &Program<'info, Token2022>,
+    wrapped_mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: wrapped_mint.clone(),
+                from: from.clone(),
+                authority: authority.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+// TLV entry of the Token-2022 metadata extension: type and length header, update authority,
+// mint, the three strings and an empty additional metadata list
+fn token_metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    4 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
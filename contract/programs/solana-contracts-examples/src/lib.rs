@@ -1,10 +1,17 @@
 #![recursion_limit = "512"]
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 pub mod constants;
 pub mod cpi;
 pub mod error;
+pub mod events;
+pub mod evm;
 pub mod instructions;
+pub mod pda;
+pub mod signing;
 pub mod state;
 
 pub use constants::*;
@@ -26,6 +33,40 @@ pub mod solana_core_contracts {
         instructions::process_vault::process_withdraw(ctx, tx)
     }
 
+    pub fn preview_deposit_erc20(
+        ctx: Context<PreviewVault>,
+        requester: Pubkey,
+        erc20_address: [u8; 20],
+        amount: u128,
+        tx_params: EvmTransactionParams,
+    ) -> Result<SignRequestPreview> {
+        instructions::preview_vault::preview_deposit_erc20(
+            ctx,
+            requester,
+            erc20_address,
+            amount,
+            tx_params,
+        )
+    }
+
+    pub fn preview_withdraw_erc20(
+        ctx: Context<PreviewWithdrawErc20>,
+        requester: Pubkey,
+        erc20_address: [u8; 20],
+        amount: u128,
+        recipient_address: [u8; 20],
+        tx_params: EvmTransactionParams,
+    ) -> Result<SignRequestPreview> {
+        instructions::preview_vault::preview_withdraw_erc20(
+            ctx,
+            requester,
+            erc20_address,
+            amount,
+            recipient_address,
+            tx_params,
+        )
+    }
+
     pub fn sign_deposit_transaction(
         ctx: Context<SignVaultTransaction>,
         tx: VaultTransaction,
@@ -34,6 +75,89 @@ pub mod solana_core_contracts {
         instructions::sign_vault::sign_deposit_transaction(ctx, tx, signing_params)
     }
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        params: VaultConfigParams,
+    ) -> Result<()> {
+        instructions::vault_config::initialize_config(ctx, params)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        params: VaultConfigParams,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::vault_config::update_config(ctx, params, new_authority)
+    }
+
+    pub fn register_mpc_signer(
+        ctx: Context<RegisterMpcSigner>,
+        key_version: u32,
+        address: [u8; 20],
+        withdrawal_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::vault_config::register_mpc_signer(
+            ctx,
+            key_version,
+            address,
+            withdrawal_address,
+        )
+    }
+
+    pub fn rotate_mpc_key(ctx: Context<RotateMpcKey>, new_key_version: u32) -> Result<()> {
+        instructions::vault_config::rotate_mpc_key(ctx, new_key_version)
+    }
+
+    pub fn revoke_mpc_signer(ctx: Context<RevokeMpcSigner>, key_version: u32) -> Result<()> {
+        instructions::vault_config::revoke_mpc_signer(ctx, key_version)
+    }
+
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, flags: PauseFlags) -> Result<()> {
+        instructions::vault_config::set_pause_flags(ctx, flags)
+    }
+
+    pub fn register_token(
+        ctx: Context<RegisterToken>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        params: TokenConfigParams,
+    ) -> Result<()> {
+        instructions::token_registry::register_token(ctx, chain_id, erc20_address, params)
+    }
+
+    pub fn update_token(
+        ctx: Context<UpdateToken>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        params: TokenConfigParams,
+    ) -> Result<()> {
+        instructions::token_registry::update_token(ctx, chain_id, erc20_address, params)
+    }
+
+    pub fn set_chain_config(
+        ctx: Context<SetChainConfig>,
+        chain_id: u64,
+        params: ChainConfigParams,
+    ) -> Result<()> {
+        instructions::chain_config::set_chain_config(ctx, chain_id, params)
+    }
+
+    pub fn sync_nonce_tracker(
+        ctx: Context<SyncNonceTracker>,
+        chain_id: u64,
+        path: String,
+        key_version: u32,
+        next_nonce: u64,
+    ) -> Result<()> {
+        instructions::nonce_tracker::sync_nonce_tracker(
+            ctx,
+            chain_id,
+            path,
+            key_version,
+            next_nonce,
+        )
+    }
+
     pub fn deposit_erc20(
         ctx: Context<DepositErc20>,
         request_id: [u8; 32],
@@ -41,6 +165,7 @@ pub mod solana_core_contracts {
         erc20_address: [u8; 20],
         amount: u128,
         tx_params: EvmTransactionParams,
+        relayer_tip: u64,
     ) -> Result<()> {
         instructions::erc20_vault::deposit_erc20(
             ctx,
@@ -49,6 +174,7 @@ pub mod solana_core_contracts {
             erc20_address,
             amount,
             tx_params,
+            relayer_tip,
         )
     }
 
@@ -61,6 +187,13 @@ pub mod solana_core_contracts {
         instructions::erc20_vault::claim_erc20(ctx, request_id, serialized_output, signature)
     }
 
+    pub fn cancel_expired_deposit(
+        ctx: Context<CancelExpiredDeposit>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::erc20_vault::cancel_expired_deposit(ctx, request_id)
+    }
+
     pub fn withdraw_erc20(
         ctx: Context<WithdrawErc20>,
         request_id: [u8; 32],
@@ -68,6 +201,8 @@ pub mod solana_core_contracts {
         amount: u128,
         recipient_address: [u8; 20],
         tx_params: EvmTransactionParams,
+        source_chain_id: u64,
+        relayer_tip: u64,
     ) -> Result<()> {
         instructions::erc20_vault::withdraw_erc20(
             ctx,
@@ -76,6 +211,8 @@ pub mod solana_core_contracts {
             amount,
             recipient_address,
             tx_params,
+            source_chain_id,
+            relayer_tip,
         )
     }
 
@@ -93,6 +230,228 @@ pub mod solana_core_contracts {
         )
     }
 
+    pub fn bump_withdrawal_fee(
+        ctx: Context<BumpWithdrawalFee>,
+        request_id: [u8; 32],
+        replacement_request_id: [u8; 32],
+        tx_params: EvmTransactionParams,
+    ) -> Result<()> {
+        instructions::erc20_vault::bump_withdrawal_fee(
+            ctx,
+            request_id,
+            replacement_request_id,
+            tx_params,
+        )
+    }
+
+    pub fn execute_queued_withdrawal(
+        ctx: Context<ExecuteQueuedWithdrawal>,
+        request_id: [u8; 32],
+        signed_request_id: [u8; 32],
+        tx_params: EvmTransactionParams,
+    ) -> Result<()> {
+        instructions::withdrawal_queue::execute_queued_withdrawal(
+            ctx,
+            request_id,
+            signed_request_id,
+            tx_params,
+        )
+    }
+
+    pub fn cancel_queued_withdrawal(
+        ctx: Context<CancelQueuedWithdrawal>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::withdrawal_queue::cancel_queued_withdrawal(ctx, request_id)
+    }
+
+    pub fn transfer_erc20_balance(
+        ctx: Context<TransferErc20Balance>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        recipient: Pubkey,
+        amount: u128,
+    ) -> Result<()> {
+        instructions::balance_transfer::transfer_erc20_balance(
+            ctx,
+            chain_id,
+            erc20_address,
+            recipient,
+            amount,
+        )
+    }
+
+    pub fn collect_fees(
+        ctx: Context<CollectFees>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        amount: u128,
+    ) -> Result<()> {
+        instructions::balance_transfer::collect_fees(ctx, chain_id, erc20_address, amount)
+    }
+
+    pub fn approve_withdrawal(
+        ctx: Context<ApproveWithdrawal>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        delegate: Pubkey,
+        amount: u128,
+        expires_at_slot: u64,
+    ) -> Result<()> {
+        instructions::allowance::approve_withdrawal(
+            ctx,
+            chain_id,
+            erc20_address,
+            delegate,
+            amount,
+            expires_at_slot,
+        )
+    }
+
+    pub fn revoke_withdrawal_allowance(
+        ctx: Context<RevokeWithdrawalAllowance>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::allowance::revoke_withdrawal_allowance(ctx, chain_id, erc20_address, delegate)
+    }
+
+    pub fn create_wrapped_mint(
+        ctx: Context<CreateWrappedMint>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+    ) -> Result<()> {
+        instructions::wrapped_token::create_wrapped_mint(ctx, chain_id, erc20_address)
+    }
+
+    pub fn wrap_erc20_balance(
+        ctx: Context<WrapErc20Balance>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        amount: u128,
+    ) -> Result<()> {
+        instructions::wrapped_token::wrap_erc20_balance(ctx, chain_id, erc20_address, amount)
+    }
+
+    pub fn unwrap_erc20_balance(
+        ctx: Context<UnwrapErc20Balance>,
+        chain_id: u64,
+        erc20_address: [u8; 20],
+        amount: u128,
+    ) -> Result<()> {
+        instructions::wrapped_token::unwrap_erc20_balance(ctx, chain_id, erc20_address, amount)
+    }
+
+    pub fn deposit_native(
+        ctx: Context<DepositNative>,
+        request_id: [u8; 32],
+        requester: Pubkey,
+        amount: u128,
+        tx_params: EvmTransactionParams,
+        relayer_tip: u64,
+    ) -> Result<()> {
+        instructions::native_vault::deposit_native(
+            ctx,
+            request_id,
+            requester,
+            amount,
+            tx_params,
+            relayer_tip,
+        )
+    }
+
+    pub fn claim_native(
+        ctx: Context<ClaimNative>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::native_vault::claim_native(ctx, request_id, serialized_output, signature)
+    }
+
+    pub fn cancel_expired_native_deposit(
+        ctx: Context<CancelExpiredNativeDeposit>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::native_vault::cancel_expired_native_deposit(ctx, request_id)
+    }
+
+    pub fn withdraw_native(
+        ctx: Context<WithdrawNative>,
+        request_id: [u8; 32],
+        amount: u128,
+        recipient_address: [u8; 20],
+        tx_params: EvmTransactionParams,
+        relayer_tip: u64,
+    ) -> Result<()> {
+        instructions::native_vault::withdraw_native(
+            ctx,
+            request_id,
+            amount,
+            recipient_address,
+            tx_params,
+            relayer_tip,
+        )
+    }
+
+    pub fn complete_withdraw_native(
+        ctx: Context<CompleteWithdrawNative>,
+        request_id: [u8; 32],
+        serialized_output: Vec<u8>,
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::native_vault::complete_withdraw_native(
+            ctx,
+            request_id,
+            serialized_output,
+            signature,
+        )
+    }
+
+    pub fn refund_expired_withdrawal(
+        ctx: Context<RefundExpiredWithdrawal>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::erc20_vault::refund_expired_withdrawal(ctx, request_id)
+    }
+
+    pub fn refund_expired_native_withdrawal(
+        ctx: Context<RefundExpiredNativeWithdrawal>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::native_vault::refund_expired_native_withdrawal(ctx, request_id)
+    }
+
+    pub fn compress_request_record(
+        ctx: Context<CompressRequestRecord>,
+        request_id: [u8; 32],
+    ) -> Result<()> {
+        instructions::request_record::compress_request_record(ctx, request_id)
+    }
+
+    pub fn record_signed_erc20_withdrawal(
+        ctx: Context<RecordSignedErc20Withdrawal>,
+        request_id: [u8; 32],
+        signed_request_id: [u8; 32],
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::signed_withdrawal::record_signed_erc20_withdrawal(
+            ctx,
+            request_id,
+            signed_request_id,
+            signature,
+        )
+    }
+
+    pub fn record_signed_native_withdrawal(
+        ctx: Context<RecordSignedNativeWithdrawal>,
+        request_id: [u8; 32],
+        signature: Signature,
+    ) -> Result<()> {
+        instructions::signed_withdrawal::record_signed_native_withdrawal(ctx, request_id, signature)
+    }
+
     pub fn sign_withdraw_transaction(
         ctx: Context<SignVaultTransaction>,
         tx: VaultTransaction,
@@ -102,30 +461,1351 @@ pub mod solana_core_contracts {
     }
 }
 
+/// Read-only accounts for previewing sign requests
 #[derive(Accounts)]
-#[instruction(request_id: [u8; 32], requester: Pubkey, erc20_address: [u8; 20], amount: u128, tx_params: EvmTransactionParams)]
-pub struct DepositErc20<'info> {
+pub struct PreviewVault<'info> {
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+}
+
+/// Withdrawal previews also need the token's protocol fee
+#[derive(Accounts)]
+#[instruction(requester: Pubkey, erc20_address: [u8; 20], amount: u128, recipient_address: [u8; 20], tx_params: EvmTransactionParams)]
+pub struct PreviewWithdrawErc20<'info> {
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// CHECK: Token registry entry, loaded in the handler to reject unregistered tokens
+    #[account(
+        seeds = [
+            b"token_config",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Must be the program's upgrade authority, so the config cannot be claimed by whoever
+    /// front-runs the deployment
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
-        mut,
-        seeds = [b"vault_authority", requester.as_ref()],
+        init,
+        payer = authority,
+        space = VaultConfig::space(),
+        seeds = [b"vault_config"],
         bump
     )]
-    pub requester_pda: SystemAccount<'info>,
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub program: Program<'info, crate::program::SolanaCoreContracts>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(key_version: u32)]
+pub struct RegisterMpcSigner<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
 
     #[account(
         init,
-        payer = payer,
-        space = PendingErc20Deposit::space(),
-        seeds = [
-            b"pending_erc20_deposit",
-            request_id.as_ref()
+        payer = authority,
+        space = MpcSigner::space(),
+        seeds = [b"mpc_signer", key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_key_version: u32)]
+pub struct RotateMpcKey<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_signer", config.current_key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub current_signer: Account<'info, MpcSigner>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_signer", new_key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub new_signer: Account<'info, MpcSigner>,
+}
+
+#[derive(Accounts)]
+#[instruction(key_version: u32)]
+pub struct RevokeMpcSigner<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mpc_signer", key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct SetChainConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainConfig::space(),
+        seeds = [b"chain_config", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, path: String, key_version: u32)]
+pub struct SyncNonceTracker<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = NonceTracker::space(),
+        seeds = [
+            b"nonce_tracker",
+            chain_id.to_le_bytes().as_ref(),
+            NonceTracker::path_seed(&path).as_ref(),
+            key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub nonce_tracker: Account<'info, NonceTracker>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct RegisterToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TokenConfig::space(),
+        seeds = [
+            b"token_config",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct UpdateToken<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"token_config",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32], requester: Pubkey, erc20_address: [u8; 20], amount: u128, tx_params: EvmTransactionParams)]
+pub struct DepositErc20<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// CHECK: Token registry entry, loaded in the handler to reject unregistered tokens
+    #[account(
+        seeds = [
+            b"token_config",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", tx_params.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_authority", requester.as_ref()],
+        bump
+    )]
+    pub requester_pda: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PendingErc20Deposit::space(),
+        seeds = [
+            b"pending_erc20_deposit",
+            request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RequestRecord::space(),
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"user_erc20_balance",
+            requester.as_ref(),
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    /// Passed to claim the deposit as wrapped tokens instead of a balance
+    #[account(
+        seeds = [
+            b"wrapped_mint",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub wrapped_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [crate::constants::CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_event_authority: AccountInfo<'info>,
+
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ClaimErc20<'info> {
+    /// Relayer submitting the response, receives the relayer tip
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_deposit.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_deposit",
+            &request_id
+        ],
+        bump,
+        close = rent_payer
+    )]
+    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        seeds = [b"mpc_signer", pending_deposit.key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
+
+    /// Protocol fees accrued on the token, only required when the deposit carries a fee
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"fee_balance",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            &pending_deposit.erc20_address
+        ],
+        bump
+    )]
+    pub fee_balance: Option<Account<'info, UserErc20Balance>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"user_erc20_balance",
+            pending_deposit.requester.as_ref(),
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            &pending_deposit.erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    /// Wrapped-mode accounts, only required when the deposit was requested wrapped
+    #[account(
+        seeds = [
+            b"token_config",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            &pending_deposit.erc20_address
+        ],
+        bump
+    )]
+    pub token_config: Option<Account<'info, TokenConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"wrapped_mint",
+            pending_deposit.chain_id.to_le_bytes().as_ref(),
+            &pending_deposit.erc20_address
+        ],
+        bump
+    )]
+    pub wrapped_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Requester's token account of the wrapped mint
+    #[account(mut)]
+    pub wrapped_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+}
+
+// Add the contexts:
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32], erc20_address: [u8; 20], amount: u128, recipient_address: [u8; 20], tx_params: EvmTransactionParams, source_chain_id: u64)]
+pub struct WithdrawErc20<'info> {
+    /// The balance owner, or a delegate they approved. Pays for the request either way
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Owner of the balance being withdrawn, `authority` itself unless delegated
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// Signer of the current key, withdrawals are sent from its withdrawal address
+    #[account(
+        seeds = [b"mpc_signer", config.current_key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub current_signer: Account<'info, MpcSigner>,
+
+    /// CHECK: Token registry entry, loaded in the handler to reject unregistered tokens
+    #[account(
+        seeds = [
+            b"token_config",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", tx_params.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingErc20Withdrawal::space(),
+        seeds = [
+            b"pending_erc20_withdrawal",
+            request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    /// Next nonce of the current key's withdrawal address on this chain, created by
+    /// `sync_nonce_tracker`
+    #[account(
+        mut,
+        seeds = [
+            b"nonce_tracker",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            NonceTracker::path_seed(&config.withdrawal_root_path).as_ref(),
+            config.current_key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub nonce_tracker: Account<'info, NonceTracker>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithdrawalRateLimit::space(),
+        seeds = [
+            b"withdrawal_rate_limit",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithdrawalRateLimit::space(),
+        seeds = [
+            b"withdrawal_rate_limit",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RequestRecord::space(),
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            owner.key().as_ref(),
+            source_chain_id.to_le_bytes().as_ref(),
+            &erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    /// Wrapped-mode accounts, passed to burn the withdrawn amount from wrapped tokens
+    #[account(
+        mut,
+        seeds = [
+            b"wrapped_mint",
+            source_chain_id.to_le_bytes().as_ref(),
+            &erc20_address
+        ],
+        bump
+    )]
+    pub wrapped_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Owner's token account of the wrapped mint, `authority` burns from it as holder or
+    /// approved delegate
+    #[account(mut)]
+    pub wrapped_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token2022>>,
+
+    /// Required when `authority` is not the owner
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_allowance",
+            owner.key().as_ref(),
+            authority.key().as_ref(),
+            source_chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub allowance: Option<Account<'info, WithdrawalAllowance>>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [crate::constants::CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_event_authority: AccountInfo<'info>,
+
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompleteWithdrawErc20<'info> {
+    /// Relayer submitting the response, receives the relayer tip
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_withdrawal.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// Keyed by the original request, the response may come from one of its fee bumps.
+    /// Closed in the handler once the withdrawal is settled.
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            pending_withdrawal.request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", pending_withdrawal.request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        seeds = [b"mpc_signer", pending_withdrawal.key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
+
+    /// Protocol fees accrued on the token, only required when the withdrawal carries a fee
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"fee_balance",
+            pending_withdrawal.source_chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub fee_balance: Option<Account<'info, UserErc20Balance>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.source_chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address,
+            pending_withdrawal.requester.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct BumpWithdrawalFee<'info> {
+    /// The requester of the stuck withdrawal
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            request_id.as_ref()
+        ],
+        bump,
+        constraint = pending_withdrawal.requester == authority.key()
+            @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", pending_withdrawal.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [crate::constants::CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_event_authority: AccountInfo<'info>,
+
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct ExecuteQueuedWithdrawal<'info> {
+    /// Anyone can send a withdrawal for signing once its delay has passed, e.g. the requester,
+    /// their delegate or a relayer. Pays for the signature request
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// Signer of the current key, withdrawals are sent from its withdrawal address
+    #[account(
+        seeds = [b"mpc_signer", config.current_key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub current_signer: Account<'info, MpcSigner>,
+
+    #[account(
+        mut,
+        seeds = [b"global_vault_authority"],
+        bump
+    )]
+    /// CHECK: This is a PDA that will be used as a signer
+    pub requester: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", pending_withdrawal.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
+    /// Next nonce of the current key's withdrawal address on this chain, created by
+    /// `sync_nonce_tracker`
+    #[account(
+        mut,
+        seeds = [
+            b"nonce_tracker",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            NonceTracker::path_seed(&config.withdrawal_root_path).as_ref(),
+            config.current_key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub nonce_tracker: Account<'info, NonceTracker>,
+
+    #[account(mut)]
+    pub fee_payer: Option<Signer<'info>>,
+
+    /// CHECK: Chain signatures state
+    #[account(
+        mut,
+        seeds = [crate::constants::CHAIN_SIGNATURES_STATE_SEED],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_state: AccountInfo<'info>,
+
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
+    #[account(
+        seeds = [b"__event_authority"],
+        bump,
+        seeds::program = chain_signatures_program.key()
+    )]
+    pub chain_signatures_event_authority: AccountInfo<'info>,
+
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub instructions: Option<AccountInfo<'info>>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CancelQueuedWithdrawal<'info> {
+    #[account(address = config.guardian @ crate::error::ErrorCode::Unauthorized)]
+    pub guardian: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_withdrawal.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            &request_id
+        ],
+        bump,
+        close = rent_payer
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.source_chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address,
+            pending_withdrawal.requester.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20], recipient: Pubkey)]
+pub struct TransferErc20Balance<'info> {
+    /// The balance owner, or a delegate they approved. Pays for the recipient's account if
+    /// it is new
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Owner of the balance being sent, `authority` itself unless delegated
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            owner.key().as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub sender_balance: Account<'info, UserErc20Balance>,
+
+    /// Required when `authority` is not the owner
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_allowance",
+            owner.key().as_ref(),
+            authority.key().as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub allowance: Option<Account<'info, WithdrawalAllowance>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"user_erc20_balance",
+            recipient.as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub recipient_balance: Account<'info, UserErc20Balance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct CollectFees<'info> {
+    /// Pays for its own balance account if it is new
+    #[account(mut, address = config.fee_authority @ crate::error::ErrorCode::Unauthorized)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fee_balance",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub fee_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_authority,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"user_erc20_balance",
+            fee_authority.key().as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub recipient_balance: Account<'info, UserErc20Balance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20], delegate: Pubkey)]
+pub struct ApproveWithdrawal<'info> {
+    /// Owner of the balance being approved
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithdrawalAllowance::space(),
+        seeds = [
+            b"withdrawal_allowance",
+            authority.key().as_ref(),
+            delegate.as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub allowance: Account<'info, WithdrawalAllowance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20], delegate: Pubkey)]
+pub struct RevokeWithdrawalAllowance<'info> {
+    /// Owner of the approved balance, receives the allowance's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"withdrawal_allowance",
+            authority.key().as_ref(),
+            delegate.as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub allowance: Account<'info, WithdrawalAllowance>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct CreateWrappedMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault_config"],
+        bump,
+        has_one = authority @ crate::error::ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [
+            b"token_config",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    /// Token-2022 mint of the wrapped token, mint authority and metadata live on the PDA itself
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            b"wrapped_mint",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump,
+        mint::decimals = token_config.wrapped_decimals(),
+        mint::authority = wrapped_mint,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = wrapped_mint,
+        extensions::metadata_pointer::metadata_address = wrapped_mint,
+    )]
+    pub wrapped_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct WrapErc20Balance<'info> {
+    /// Owner of the balance, pays for their token account if it is new
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_erc20_balance",
+            authority.key().as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        seeds = [
+            b"token_config",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"wrapped_mint",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump,
+        mint::token_program = token_program
+    )]
+    pub wrapped_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = wrapped_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(chain_id: u64, erc20_address: [u8; 20])]
+pub struct UnwrapErc20Balance<'info> {
+    /// Holder of the wrapped tokens, credited with the unwrapped balance
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = UserErc20Balance::space(),
+        seeds = [
+            b"user_erc20_balance",
+            authority.key().as_ref(),
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserErc20Balance>,
+
+    #[account(
+        seeds = [
+            b"token_config",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"wrapped_mint",
+            chain_id.to_le_bytes().as_ref(),
+            erc20_address.as_ref()
+        ],
+        bump,
+        mint::token_program = token_program
+    )]
+    pub wrapped_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = wrapped_mint,
+        token::authority = authority,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32], requester: Pubkey, amount: u128, tx_params: EvmTransactionParams)]
+pub struct DepositNative<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// CHECK: Token registry entry, loaded in the handler to reject unregistered tokens
+    #[account(
+        seeds = [
+            b"token_config",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", tx_params.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_authority", requester.as_ref()],
+        bump
+    )]
+    pub requester_pda: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PendingNativeDeposit::space(),
+        seeds = [
+            b"pending_native_deposit",
+            request_id.as_ref()
+        ],
+        bump
+    )]
+    pub pending_deposit: Account<'info, PendingNativeDeposit>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = RequestRecord::space(),
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserNativeBalance::space(),
+        seeds = [
+            b"user_native_balance",
+            requester.as_ref(),
+            tx_params.chain_id.to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+    pub user_balance: Account<'info, UserNativeBalance>,
 
     #[account(mut)]
     pub fee_payer: Option<Signer<'info>>,
@@ -139,60 +1819,117 @@ pub struct DepositErc20<'info> {
     )]
     pub chain_signatures_state: AccountInfo<'info>,
 
-    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
     #[account(
         seeds = [b"__event_authority"],
         bump,
         seeds::program = chain_signatures_program.key()
     )]
-    pub event_authority: AccountInfo<'info>,
+    pub chain_signatures_event_authority: AccountInfo<'info>,
 
-    pub chain_signatures_program:
-        Program<'info, ::chain_signatures::program::ChainSignaturesProject>,
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     pub instructions: Option<AccountInfo<'info>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(request_id: [u8; 32])]
-pub struct ClaimErc20<'info> {
+pub struct ClaimNative<'info> {
+    /// Relayer submitting the response, receives the relayer tip
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_deposit.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
     #[account(
         mut,
         seeds = [
-            b"pending_erc20_deposit",
+            b"pending_native_deposit",
             &request_id
         ],
         bump,
-        close = payer
+        close = rent_payer
     )]
-    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+    pub pending_deposit: Account<'info, PendingNativeDeposit>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        seeds = [b"mpc_signer", pending_deposit.key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
 
     #[account(
         init_if_needed,
         payer = payer,
-        space = UserErc20Balance::space(),
+        space = UserNativeBalance::space(),
         seeds = [
-            b"user_erc20_balance",
+            b"user_native_balance",
             pending_deposit.requester.as_ref(),
-            &pending_deposit.erc20_address
+            pending_deposit.chain_id.to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub user_balance: Account<'info, UserErc20Balance>,
+    pub user_balance: Account<'info, UserNativeBalance>,
 
     pub system_program: Program<'info, System>,
 }
 
 // Add the contexts:
+
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(request_id: [u8; 32], erc20_address: [u8; 20], amount: u128, recipient_address: [u8; 20], tx_params: EvmTransactionParams)]
-pub struct WithdrawErc20<'info> {
+#[instruction(request_id: [u8; 32], amount: u128, recipient_address: [u8; 20], tx_params: EvmTransactionParams)]
+pub struct WithdrawNative<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// Signer of the current key, withdrawals are sent from its withdrawal address
+    #[account(
+        seeds = [b"mpc_signer", config.current_key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub current_signer: Account<'info, MpcSigner>,
+
+    /// CHECK: Token registry entry, loaded in the handler to reject unregistered tokens
+    #[account(
+        seeds = [
+            b"token_config",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref()
+        ],
+        bump
+    )]
+    pub token_config: UncheckedAccount<'info>,
+
+    /// CHECK: Chain gas limits, loaded in the handler to reject unconfigured chains
+    #[account(
+        seeds = [b"chain_config", tx_params.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_config: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"global_vault_authority"],
@@ -204,26 +1941,76 @@ pub struct WithdrawErc20<'info> {
     #[account(
         init,
         payer = authority,
-        space = PendingErc20Withdrawal::space(),
+        space = PendingNativeWithdrawal::space(),
         seeds = [
-            b"pending_erc20_withdrawal",
+            b"pending_native_withdrawal",
             request_id.as_ref()
         ],
         bump
     )]
-    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+    pub pending_withdrawal: Account<'info, PendingNativeWithdrawal>,
 
+    /// Next nonce of the current key's withdrawal address on this chain, created by
+    /// `sync_nonce_tracker`
     #[account(
         mut,
         seeds = [
-            b"user_erc20_balance",
+            b"nonce_tracker",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            NonceTracker::path_seed(&config.withdrawal_root_path).as_ref(),
+            config.current_key_version.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub nonce_tracker: Account<'info, NonceTracker>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithdrawalRateLimit::space(),
+        seeds = [
+            b"withdrawal_rate_limit",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref()
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WithdrawalRateLimit::space(),
+        seeds = [
+            b"withdrawal_rate_limit",
+            tx_params.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref(),
+            authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RequestRecord::space(),
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_native_balance",
             authority.key().as_ref(),
-            &erc20_address
+            tx_params.chain_id.to_le_bytes().as_ref()
         ],
         bump,
         constraint = user_balance.amount >= amount
     )]
-    pub user_balance: Account<'info, UserErc20Balance>,
+    pub user_balance: Account<'info, UserNativeBalance>,
 
     #[account(mut)]
     pub fee_payer: Option<Signer<'info>>,
@@ -237,47 +2024,336 @@ pub struct WithdrawErc20<'info> {
     )]
     pub chain_signatures_state: AccountInfo<'info>,
 
-    /// CHECK: Event authority for CPI events, PDA with seed "__event_authority"
+    /// CHECK: Chain signatures event authority, PDA with seed "__event_authority"
     #[account(
         seeds = [b"__event_authority"],
         bump,
         seeds::program = chain_signatures_program.key()
     )]
-    pub event_authority: AccountInfo<'info>,
+    pub chain_signatures_event_authority: AccountInfo<'info>,
 
-    pub chain_signatures_program:
-        Program<'info, ::chain_signatures::program::ChainSignaturesProject>,
+    /// CHECK: Must match the chain signatures program recorded in the vault config
+    #[account(
+        executable,
+        address = config.chain_signatures_program
+            @ crate::error::ErrorCode::InvalidChainSignaturesProgram
+    )]
+    pub chain_signatures_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     pub instructions: Option<AccountInfo<'info>>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(request_id: [u8; 32])]
-pub struct CompleteWithdrawErc20<'info> {
+pub struct CompleteWithdrawNative<'info> {
+    /// Relayer submitting the response, receives the relayer tip
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_withdrawal.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
     #[account(
         mut,
         seeds = [
-            b"pending_erc20_withdrawal",
+            b"pending_native_withdrawal",
+            &request_id
+        ],
+        bump,
+        close = rent_payer
+    )]
+    pub pending_withdrawal: Account<'info, PendingNativeWithdrawal>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        seeds = [b"mpc_signer", pending_withdrawal.key_version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mpc_signer: Account<'info, MpcSigner>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_native_balance",
+            pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.chain_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserNativeBalance>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref()
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref(),
+            pending_withdrawal.requester.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CancelExpiredDeposit<'info> {
+    /// The requester whose deposit is being abandoned
+    pub requester: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_deposit.payer)]
+    pub payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_deposit",
             &request_id
         ],
         bump,
+        has_one = requester @ crate::error::ErrorCode::Unauthorized,
         close = payer
     )]
+    pub pending_deposit: Account<'info, PendingErc20Deposit>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CancelExpiredNativeDeposit<'info> {
+    /// The requester whose deposit is being abandoned
+    pub requester: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_deposit.payer)]
+    pub payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_native_deposit",
+            &request_id
+        ],
+        bump,
+        has_one = requester @ crate::error::ErrorCode::Unauthorized,
+        close = payer
+    )]
+    pub pending_deposit: Account<'info, PendingNativeDeposit>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RefundExpiredWithdrawal<'info> {
+    /// The requester whose withdrawal never got a response
+    pub requester: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_withdrawal.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_erc20_withdrawal",
+            &request_id
+        ],
+        bump,
+        has_one = requester @ crate::error::ErrorCode::Unauthorized,
+        close = rent_payer
+    )]
     pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
 
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
     #[account(
         mut,
         seeds = [
             b"user_erc20_balance",
             pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.source_chain_id.to_le_bytes().as_ref(),
             &pending_withdrawal.erc20_address
         ],
         bump
     )]
     pub user_balance: Account<'info, UserErc20Balance>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            &pending_withdrawal.erc20_address,
+            pending_withdrawal.requester.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RefundExpiredNativeWithdrawal<'info> {
+    /// The requester whose withdrawal never got a response
+    pub requester: Signer<'info>,
+
+    /// CHECK: Original rent payer, receives the lamports of the closed record
+    #[account(mut, address = pending_withdrawal.payer)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pending_native_withdrawal",
+            &request_id
+        ],
+        bump,
+        has_one = requester @ crate::error::ErrorCode::Unauthorized,
+        close = rent_payer
+    )]
+    pub pending_withdrawal: Account<'info, PendingNativeWithdrawal>,
+
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump
+    )]
+    pub request_record: Account<'info, RequestRecord>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_native_balance",
+            pending_withdrawal.requester.as_ref(),
+            pending_withdrawal.chain_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub user_balance: Account<'info, UserNativeBalance>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref()
+        ],
+        bump
+    )]
+    pub token_rate_limit: Account<'info, WithdrawalRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal_rate_limit",
+            pending_withdrawal.chain_id.to_le_bytes().as_ref(),
+            crate::constants::NATIVE_TOKEN_ADDRESS.as_ref(),
+            pending_withdrawal.requester.as_ref()
+        ],
+        bump
+    )]
+    pub user_rate_limit: Account<'info, WithdrawalRateLimit>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct CompressRequestRecord<'info> {
+    /// The request's requester or the vault authority
+    pub signer: Signer<'info>,
+
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, VaultConfig>,
+
+    /// CHECK: Original rent payer, checked against the record in the handler
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// CHECK: Loaded in the handler and rewritten as a `RequestTombstone`, never closed
+    #[account(
+        mut,
+        seeds = [b"request_record", request_id.as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub request_record: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RecordSignedErc20Withdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_erc20_withdrawal", request_id.as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingErc20Withdrawal>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(request_id: [u8; 32])]
+pub struct RecordSignedNativeWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_native_withdrawal", request_id.as_ref()],
+        bump
+    )]
+    pub pending_withdrawal: Account<'info, PendingNativeWithdrawal>,
 }
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::constants::CHAIN_SIGNATURES_STATE_SEED;
+use crate::state::nonce::NonceTracker;
+
+// Off-chain derivation of every vault PDA, seeds mirror the account constraints in lib.rs
+
+pub fn vault_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_config"], &crate::ID)
+}
+
+/// Loader account holding the program's upgrade authority, checked by `initialize_config`
+pub fn program_data() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID)
+}
+
+pub fn mpc_signer(key_version: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mpc_signer", &key_version.to_le_bytes()], &crate::ID)
+}
+
+pub fn token_config(chain_id: u64, erc20_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"token_config", &chain_id.to_le_bytes(), erc20_address],
+        &crate::ID,
+    )
+}
+
+pub fn chain_config(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"chain_config", &chain_id.to_le_bytes()], &crate::ID)
+}
+
+/// Per-user requester of deposit signatures, its string form is the derivation path
+pub fn vault_authority(requester: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_authority", requester.as_ref()], &crate::ID)
+}
+
+/// Requester of all withdrawal signatures
+pub fn global_vault_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"global_vault_authority"], &crate::ID)
+}
+
+/// Next nonce of the address derived under `path` and `key_version` on `chain_id`
+pub fn nonce_tracker(chain_id: u64, path: &str, key_version: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"nonce_tracker",
+            &chain_id.to_le_bytes(),
+            &NonceTracker::path_seed(path),
+            &key_version.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Vault-wide rolling withdrawal usage of a token
+pub fn withdrawal_rate_limit(chain_id: u64, token: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"withdrawal_rate_limit", &chain_id.to_le_bytes(), token],
+        &crate::ID,
+    )
+}
+
+/// Rolling withdrawal usage of a token by one user
+pub fn user_withdrawal_rate_limit(chain_id: u64, token: &[u8; 20], user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"withdrawal_rate_limit",
+            &chain_id.to_le_bytes(),
+            token,
+            user.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Part of `owner`'s balance `delegate` may withdraw or transfer
+pub fn withdrawal_allowance(
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    chain_id: u64,
+    erc20_address: &[u8; 20],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"withdrawal_allowance",
+            owner.as_ref(),
+            delegate.as_ref(),
+            &chain_id.to_le_bytes(),
+            erc20_address,
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn pending_erc20_deposit(request_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pending_erc20_deposit", request_id], &crate::ID)
+}
+
+pub fn pending_erc20_withdrawal(request_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pending_erc20_withdrawal", request_id], &crate::ID)
+}
+
+pub fn user_erc20_balance(user: &Pubkey, chain_id: u64, erc20_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"user_erc20_balance",
+            user.as_ref(),
+            &chain_id.to_le_bytes(),
+            erc20_address,
+        ],
+        &crate::ID,
+    )
+}
+
+/// Protocol fees accrued on `erc20_address`, collected by the fee authority
+pub fn fee_balance(chain_id: u64, erc20_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"fee_balance", &chain_id.to_le_bytes(), erc20_address],
+        &crate::ID,
+    )
+}
+
+/// Token-2022 mint wrapping balances of `erc20_address`, also its own mint authority
+pub fn wrapped_mint(chain_id: u64, erc20_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"wrapped_mint", &chain_id.to_le_bytes(), erc20_address],
+        &crate::ID,
+    )
+}
+
+pub fn pending_native_deposit(request_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pending_native_deposit", request_id], &crate::ID)
+}
+
+pub fn pending_native_withdrawal(request_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"pending_native_withdrawal", request_id], &crate::ID)
+}
+
+pub fn user_native_balance(user: &Pubkey, chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"user_native_balance",
+            user.as_ref(),
+            &chain_id.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn request_record(request_id: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"request_record", request_id], &crate::ID)
+}
+
+/// Authority this program emits its CPI events through
+pub fn event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &crate::ID)
+}
+
+pub fn chain_signatures_state(chain_signatures_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CHAIN_SIGNATURES_STATE_SEED], chain_signatures_program)
+}
+
+pub fn chain_signatures_event_authority(chain_signatures_program: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], chain_signatures_program)
+}
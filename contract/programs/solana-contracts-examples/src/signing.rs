@@ -0,0 +1,117 @@
+use alloy_sol_types::SolValue;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use chain_signatures::cpi::accounts::SignRespond;
+use chain_signatures::cpi::sign_respond;
+use chain_signatures::SerializationFormat;
+
+// Parameters every vault `sign_respond` request is made with
+pub const SLIP44_ETHEREUM: u32 = 60;
+pub const SIGNATURE_ALGO: &str = "ECDSA";
+pub const SIGNATURE_DEST: &str = "ethereum";
+pub const SIGNATURE_PARAMS: &str = "";
+
+pub fn generate_sign_respond_request_id(
+    sender: &Pubkey,
+    transaction_data: &[u8],
+    slip44_chain_id: u32,
+    key_version: u32,
+    path: &str,
+    algo: &str,
+    dest: &str,
+    params: &str,
+) -> [u8; 32] {
+    // Match TypeScript implementation using ABI encoding
+    let encoded = (
+        sender.to_string(),
+        transaction_data,
+        slip44_chain_id,
+        key_version,
+        path,
+        algo,
+        dest,
+        params,
+    )
+        .abi_encode_packed();
+
+    keccak::hash(&encoded).to_bytes()
+}
+
+/// Request id of a vault EVM transaction, as computed by the chain signatures program
+pub fn evm_request_id(
+    sender: &Pubkey,
+    rlp_encoded_tx: &[u8],
+    key_version: u32,
+    path: &str,
+) -> [u8; 32] {
+    generate_sign_respond_request_id(
+        sender,
+        rlp_encoded_tx,
+        SLIP44_ETHEREUM,
+        key_version,
+        path,
+        SIGNATURE_ALGO,
+        SIGNATURE_DEST,
+        SIGNATURE_PARAMS,
+    )
+}
+
+/// Message the MPC network signs when responding to a request
+pub fn hash_message(request_id: &[u8; 32], serialized_output: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(32 + serialized_output.len());
+    data.extend_from_slice(request_id);
+    data.extend_from_slice(serialized_output);
+
+    keccak::hash(&data).to_bytes()
+}
+
+/// Accounts a vault `sign_respond` CPI is made with, `requester` being the PDA that signs it
+pub struct SignRequestAccounts<'a, 'info> {
+    pub chain_signatures_program: &'a AccountInfo<'info>,
+    pub chain_signatures_state: &'a AccountInfo<'info>,
+    pub chain_signatures_event_authority: &'a AccountInfo<'info>,
+    pub requester: AccountInfo<'info>,
+    pub fee_payer: Option<&'a Signer<'info>>,
+    pub system_program: &'a Program<'info, System>,
+    pub instructions: Option<&'a AccountInfo<'info>>,
+}
+
+/// Requests an MPC signature over an EVM transaction, answered with an output decoded by
+/// the explorer schema (ABI) and delivered with the callback schema (Borsh)
+pub fn request_evm_signature<'info>(
+    accounts: SignRequestAccounts<'_, 'info>,
+    signer_seeds: &[&[&[u8]]],
+    rlp_encoded_tx: Vec<u8>,
+    key_version: u32,
+    path: String,
+    (explorer_schema, callback_schema): (Vec<u8>, Vec<u8>),
+) -> Result<()> {
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.chain_signatures_program.clone(),
+        SignRespond {
+            program_state: accounts.chain_signatures_state.clone(),
+            requester: accounts.requester,
+            fee_payer: accounts.fee_payer.map(|fp| fp.to_account_info()),
+            system_program: accounts.system_program.to_account_info(),
+            instructions: accounts.instructions.cloned(),
+            event_authority: accounts.chain_signatures_event_authority.clone(),
+            program: accounts.chain_signatures_program.clone(),
+        },
+        signer_seeds,
+    );
+
+    sign_respond(
+        cpi_ctx,
+        rlp_encoded_tx,
+        SLIP44_ETHEREUM,
+        key_version,
+        path,
+        SIGNATURE_ALGO.to_string(),
+        SIGNATURE_DEST.to_string(),
+        SIGNATURE_PARAMS.to_string(),
+        SerializationFormat::AbiJson,
+        explorer_schema,
+        SerializationFormat::Borsh,
+        callback_schema,
+    )
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Part of an owner's ERC20 balance a delegate may withdraw or transfer on their behalf
+#[account]
+pub struct WithdrawalAllowance {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    /// Chain the approved balance is held on
+    pub chain_id: u64,
+    pub erc20_address: [u8; 20],
+    /// Amount left to spend, withdrawals that are later refunded still count against it
+    pub amount: u128,
+    pub expires_at_slot: u64,
+}
+
+impl WithdrawalAllowance {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // owner
+        32 + // delegate
+        8 + // chain_id
+        20 + // erc20_address
+        16 + // amount
+        8 // expires_at_slot
+    }
+
+    /// Lets `delegate` move `amount` of `owner`'s balance. Owners spend their own balance
+    /// freely, anyone else needs an unexpired allowance covering the amount.
+    pub fn spend(
+        allowance: Option<&mut Self>,
+        owner: &Pubkey,
+        delegate: &Pubkey,
+        amount: u128,
+    ) -> Result<()> {
+        if owner == delegate {
+            return Ok(());
+        }
+
+        let allowance = allowance.ok_or(crate::error::ErrorCode::AllowanceMissing)?;
+        require!(
+            Clock::get()?.slot < allowance.expires_at_slot,
+            crate::error::ErrorCode::AllowanceExpired
+        );
+
+        allowance.amount = allowance
+            .amount
+            .checked_sub(amount)
+            .ok_or(crate::error::ErrorCode::AllowanceExceeded)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::vault::EvmTransactionParams;
+
+/// Gas guardrails for transactions the vault has signed on a given EVM chain
+#[account]
+pub struct ChainConfig {
+    pub chain_id: u64,
+    pub max_gas_limit: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
+
+impl ChainConfig {
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // chain_id
+        16 + // max_gas_limit
+        16 + // max_fee_per_gas
+        16 // max_priority_fee_per_gas
+    }
+
+    /// Loads the guardrails of a chain, rejecting chains that were never configured
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require!(
+            account.owner == &crate::ID && !account.data_is_empty(),
+            crate::error::ErrorCode::ChainNotConfigured
+        );
+
+        let data = account.try_borrow_data()?;
+        Self::try_deserialize(&mut &data[..])
+            .map_err(|_| crate::error::ErrorCode::ChainNotConfigured.into())
+    }
+
+    pub fn apply(&mut self, params: ChainConfigParams) -> Result<()> {
+        require!(
+            params.max_gas_limit > 0 && params.max_fee_per_gas > 0,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.max_priority_fee_per_gas <= params.max_fee_per_gas,
+            crate::error::ErrorCode::InvalidConfig
+        );
+
+        self.max_gas_limit = params.max_gas_limit;
+        self.max_fee_per_gas = params.max_fee_per_gas;
+        self.max_priority_fee_per_gas = params.max_priority_fee_per_gas;
+
+        Ok(())
+    }
+
+    /// Checks an ERC20 transfer before it is built, the gas it burns is paid by the vault
+    pub fn require_token_transfer_allowed(&self, tx_params: &EvmTransactionParams) -> Result<()> {
+        require!(
+            tx_params.value == 0,
+            crate::error::ErrorCode::InvalidTransactionValue
+        );
+        self.require_gas_allowed(tx_params)
+    }
+
+    pub fn require_gas_allowed(&self, tx_params: &EvmTransactionParams) -> Result<()> {
+        require!(
+            tx_params.gas_limit <= self.max_gas_limit,
+            crate::error::ErrorCode::GasLimitTooHigh
+        );
+        require!(
+            tx_params.max_fee_per_gas <= self.max_fee_per_gas
+                && tx_params.max_priority_fee_per_gas <= self.max_priority_fee_per_gas,
+            crate::error::ErrorCode::GasFeeTooHigh
+        );
+
+        Ok(())
+    }
+}
+
+/// Settable fields of a chain config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ChainConfigParams {
+    pub max_gas_limit: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+
+/// Admin-controlled configuration for the ERC20 vault
+#[account]
+pub struct VaultConfig {
+    /// Administrator allowed to update this config
+    pub authority: Pubkey,
+    /// Emergency role allowed to pause vault operations and cancel queued withdrawals
+    pub guardian: Pubkey,
+    /// Currently paused vault operations
+    pub pause: PauseFlags,
+    /// EVM address that deposits are swept to
+    pub sweep_recipient: [u8; 20],
+    /// Derivation path used for withdrawals from the vault
+    pub withdrawal_root_path: String,
+    /// Chain signatures program used for `sign_respond` requests
+    pub chain_signatures_program: Pubkey,
+    /// MPC key version new requests are signed under
+    pub current_key_version: u32,
+    /// Seconds a rotated-out MPC signer stays trusted for in-flight requests
+    pub key_rotation_grace_period: i64,
+    /// EVM chains whose token liquidity is interchangeable for withdrawals
+    pub fungible_chain_ids: Vec<u64>,
+    /// Slots a pending deposit waits for a response before it can be cancelled
+    pub deposit_timeout_slots: u64,
+    /// Slots a pending withdrawal waits for a response before its balance can be restored
+    pub withdrawal_refund_delay_slots: u64,
+    /// Owner of the balances protocol fees accrue to, withdraws them like any user
+    pub fee_authority: Pubkey,
+    /// Slots a withdrawal above its token's threshold stays queued before it can be signed
+    pub large_withdrawal_delay_slots: u64,
+}
+
+impl VaultConfig {
+    pub const MAX_PATH_LEN: usize = 64;
+    pub const MAX_FUNGIBLE_CHAINS: usize = 8;
+    /// ~7 days of slots. A signed withdrawal can still land on the EVM side until its nonce is
+    /// consumed, so the refund delay has to outlast any realistic rebroadcast window.
+    pub const MIN_WITHDRAWAL_REFUND_DELAY_SLOTS: u64 = 1_512_000;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // authority
+        32 + // guardian
+        PauseFlags::SIZE + // pause
+        20 + // sweep_recipient
+        4 + Self::MAX_PATH_LEN + // withdrawal_root_path
+        32 + // chain_signatures_program
+        4 + // current_key_version
+        8 + // key_rotation_grace_period
+        4 + 8 * Self::MAX_FUNGIBLE_CHAINS + // fungible_chain_ids
+        8 + // deposit_timeout_slots
+        8 + // withdrawal_refund_delay_slots
+        32 + // fee_authority
+        8 // large_withdrawal_delay_slots
+    }
+
+    pub fn apply(&mut self, params: VaultConfigParams) -> Result<()> {
+        require!(
+            params.withdrawal_root_path.len() <= Self::MAX_PATH_LEN,
+            crate::error::ErrorCode::PathTooLong
+        );
+        require!(
+            params.key_rotation_grace_period >= 0,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.fungible_chain_ids.len() <= Self::MAX_FUNGIBLE_CHAINS,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.deposit_timeout_slots > 0,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.withdrawal_refund_delay_slots >= Self::MIN_WITHDRAWAL_REFUND_DELAY_SLOTS,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.large_withdrawal_delay_slots > 0,
+            crate::error::ErrorCode::InvalidConfig
+        );
+
+        self.guardian = params.guardian;
+        self.sweep_recipient = params.sweep_recipient;
+        self.withdrawal_root_path = params.withdrawal_root_path;
+        self.chain_signatures_program = params.chain_signatures_program;
+        self.key_rotation_grace_period = params.key_rotation_grace_period;
+        self.fungible_chain_ids = params.fungible_chain_ids;
+        self.deposit_timeout_slots = params.deposit_timeout_slots;
+        self.withdrawal_refund_delay_slots = params.withdrawal_refund_delay_slots;
+        self.fee_authority = params.fee_authority;
+        self.large_withdrawal_delay_slots = params.large_withdrawal_delay_slots;
+
+        Ok(())
+    }
+
+    /// Whether a balance deposited on `source_chain_id` may be withdrawn on `destination_chain_id`
+    pub fn can_withdraw_across(&self, source_chain_id: u64, destination_chain_id: u64) -> bool {
+        source_chain_id == destination_chain_id
+            || (self.fungible_chain_ids.contains(&source_chain_id)
+                && self.fungible_chain_ids.contains(&destination_chain_id))
+    }
+
+    pub fn require_active(&self, action: PausableAction) -> Result<()> {
+        require!(
+            !self.pause.is_paused(action),
+            crate::error::ErrorCode::OperationPaused
+        );
+
+        Ok(())
+    }
+}
+
+/// Per-operation circuit breakers for the vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PauseFlags {
+    /// Pauses every operation below
+    pub all: bool,
+    pub deposits: bool,
+    pub claims: bool,
+    pub withdrawals: bool,
+    /// Only blocks completions that settle a withdrawal, refunds stay allowed
+    pub completions: bool,
+}
+
+impl PauseFlags {
+    pub const SIZE: usize = 5;
+
+    pub fn is_paused(&self, action: PausableAction) -> bool {
+        self.all
+            || match action {
+                PausableAction::Deposit => self.deposits,
+                PausableAction::Claim => self.claims,
+                PausableAction::Withdrawal => self.withdrawals,
+                PausableAction::Completion => self.completions,
+            }
+    }
+
+    /// Returns true if any flag set in `self` is cleared in `next`
+    pub fn is_lifted_by(&self, next: &PauseFlags) -> bool {
+        (self.all && !next.all)
+            || (self.deposits && !next.deposits)
+            || (self.claims && !next.claims)
+            || (self.withdrawals && !next.withdrawals)
+            || (self.completions && !next.completions)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PausableAction {
+    Deposit,
+    Claim,
+    Withdrawal,
+    Completion,
+}
+
+/// Settable fields of the vault config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VaultConfigParams {
+    /// Emergency role allowed to pause vault operations
+    pub guardian: Pubkey,
+    /// EVM address that deposits are swept to
+    pub sweep_recipient: [u8; 20],
+    /// Derivation path used for withdrawals from the vault
+    pub withdrawal_root_path: String,
+    /// Chain signatures program used for `sign_respond` requests
+    pub chain_signatures_program: Pubkey,
+    /// Seconds a rotated-out MPC signer stays trusted for in-flight requests
+    pub key_rotation_grace_period: i64,
+    /// EVM chains whose token liquidity is interchangeable for withdrawals
+    pub fungible_chain_ids: Vec<u64>,
+    /// Slots a pending deposit waits for a response before it can be cancelled
+    pub deposit_timeout_slots: u64,
+    /// Slots a pending withdrawal waits for a response before its balance can be restored
+    pub withdrawal_refund_delay_slots: u64,
+    /// Owner of the balances protocol fees accrue to
+    pub fee_authority: Pubkey,
+    /// Slots a withdrawal above its token's threshold stays queued before it can be signed
+    pub large_withdrawal_delay_slots: u64,
+}
+
+/// Trusted MPC response signer for a single key version
+#[account]
+pub struct MpcSigner {
+    /// Key version passed to `sign_respond` for requests answered by this signer
+    pub key_version: u32,
+    /// EVM address recovered from valid response signatures
+    pub address: [u8; 20],
+    /// EVM address of the withdrawal root path under this key, withdrawals signed with the key
+    /// are sent from it
+    pub withdrawal_address: [u8; 20],
+    /// Unix timestamp after which responses from this signer are rejected
+    pub valid_until: i64,
+}
+
+impl MpcSigner {
+    pub fn space() -> usize {
+        8 + // discriminator
+        4 + // key_version
+        20 + // address
+        20 + // withdrawal_address
+        8 // valid_until
+    }
+
+    pub fn is_trusted(&self, now: i64) -> bool {
+        now < self.valid_until
+    }
+}
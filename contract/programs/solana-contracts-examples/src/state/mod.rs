@@ -1,5 +1,19 @@
+pub mod allowance;
+pub mod chain;
 pub mod chain_signatures;
+pub mod config;
+pub mod nonce;
+pub mod rate_limit;
+pub mod request;
+pub mod token;
 pub mod vault;
 
+pub use allowance::*;
+pub use chain::*;
 pub use chain_signatures::*;
+pub use config::*;
+pub use nonce::*;
+pub use rate_limit::*;
+pub use request::*;
+pub use token::*;
 pub use vault::*;
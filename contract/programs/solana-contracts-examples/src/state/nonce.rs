@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Next EVM nonce of an MPC-derived address, so concurrent requests cannot collide. Each key
+/// version derives a different address, its nonces are tracked separately
+#[account]
+pub struct NonceTracker {
+    pub chain_id: u64,
+    /// Derivation path of the tracked address
+    pub path: String,
+    /// Key version the address is derived under
+    pub key_version: u32,
+    /// Nonce the next request signed under `path` must use
+    pub next_nonce: u64,
+}
+
+impl NonceTracker {
+    pub const MAX_PATH_LEN: usize = 64;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // chain_id
+        4 + Self::MAX_PATH_LEN + // path
+        4 + // key_version
+        8 // next_nonce
+    }
+
+    /// Paths can exceed the 32 byte seed limit, PDAs are keyed by their hash
+    pub fn path_seed(path: &str) -> [u8; 32] {
+        keccak::hash(path.as_bytes()).to_bytes()
+    }
+
+    /// Takes `nonce` if it is the next one, rejecting reused and skipped nonces
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        require!(
+            nonce == self.next_nonce,
+            crate::error::ErrorCode::InvalidNonce
+        );
+
+        self.next_nonce = nonce
+            .checked_add(1)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+/// Withdrawals counted against a token's rolling limit, either vault-wide or for one user
+#[account]
+pub struct WithdrawalRateLimit {
+    /// Usage within the window starting at `window_start_slot`
+    pub used: u128,
+    /// Usage within the window before it
+    pub previous_used: u128,
+    /// First slot of the current window, windows are aligned to multiples of `window_slots`
+    pub window_start_slot: u64,
+    /// Window length the usage was counted under
+    pub window_slots: u64,
+}
+
+impl WithdrawalRateLimit {
+    pub fn space() -> usize {
+        8 + // discriminator
+        16 + // used
+        16 + // previous_used
+        8 + // window_start_slot
+        8 // window_slots
+    }
+
+    /// Counts `amount` against `limit` per `window_slots`, a zero limit disables the check.
+    /// Usage over the trailing window is estimated as the current window's usage plus the
+    /// previous window's, weighted by how much of it the trailing window still overlaps.
+    pub fn consume(
+        &mut self,
+        amount: u128,
+        limit: u128,
+        window_slots: u64,
+        slot: u64,
+    ) -> Result<()> {
+        if limit == 0 {
+            return Ok(());
+        }
+
+        self.roll(window_slots, slot);
+
+        let window = window_slots as u128;
+        let overlap = window - (slot - self.window_start_slot) as u128;
+        // Rounded up, and counted in full if the product overflows
+        let previous = self
+            .previous_used
+            .checked_mul(overlap)
+            .map_or(self.previous_used, |scaled| scaled.div_ceil(window));
+
+        let used = self
+            .used
+            .checked_add(amount)
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+        require!(
+            used.saturating_add(previous) <= limit,
+            crate::error::ErrorCode::WithdrawalRateLimited
+        );
+
+        self.used = used;
+
+        Ok(())
+    }
+
+    /// Gives back `amount` charged at `charged_slot` for a withdrawal that never went out.
+    /// Charges older than the previous window no longer count and are left alone.
+    pub fn release(&mut self, amount: u128, charged_slot: u64) {
+        if charged_slot >= self.window_start_slot {
+            self.used = self.used.saturating_sub(amount);
+        } else if charged_slot >= self.window_start_slot.saturating_sub(self.window_slots) {
+            self.previous_used = self.previous_used.saturating_sub(amount);
+        }
+    }
+
+    // Moves to the window containing `slot`. A changed window length carries everything
+    // counted so far into the previous window rather than forgetting it.
+    fn roll(&mut self, window_slots: u64, slot: u64) {
+        let window_start_slot = slot - slot % window_slots;
+
+        if window_slots != self.window_slots {
+            self.previous_used = self.previous_used.saturating_add(self.used);
+            self.used = 0;
+        } else if window_start_slot != self.window_start_slot {
+            self.previous_used = if window_start_slot == self.window_start_slot + window_slots {
+                self.used
+            } else {
+                0
+            };
+            self.used = 0;
+        }
+
+        self.window_start_slot = window_start_slot;
+        self.window_slots = window_slots;
+    }
+}
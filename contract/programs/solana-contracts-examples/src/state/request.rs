@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Kind of vault operation a request was made for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestKind {
+    Erc20Deposit,
+    Erc20Withdrawal,
+    NativeDeposit,
+    NativeWithdrawal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestStatus {
+    /// Waiting for the MPC response
+    Pending,
+    /// Deposit credited or withdrawal executed
+    Claimed,
+    /// Deposit sweep failed on the EVM side, nothing was credited
+    Failed,
+    /// Withdrawal failed on the EVM side and the balance was restored
+    Refunded,
+    /// No response arrived in time, the deposit was cancelled or the withdrawal restored
+    Expired,
+    /// Queued withdrawal vetoed by the guardian before it was signed, the balance was restored
+    Cancelled,
+}
+
+/// Permanent trace of a request, outlives the pending account that is closed on settlement
+#[account]
+pub struct RequestRecord {
+    pub request_id: [u8; 32],
+    pub requester: Pubkey,
+    /// Account that paid the rent for this record, gets all but the tombstone's back on compression
+    pub payer: Pubkey,
+    pub kind: RequestKind,
+    /// ERC20 address, NATIVE_TOKEN_ADDRESS for native ETH
+    pub token: [u8; 20],
+    pub chain_id: u64,
+    pub amount: u128,
+    pub status: RequestStatus,
+    /// keccak256 of the serialized MPC response, zero if none was delivered
+    pub output_hash: [u8; 32],
+    pub created_slot: u64,
+    pub completed_slot: u64,
+}
+
+impl RequestRecord {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // request_id
+        32 + // requester
+        32 + // payer
+        1 + // kind
+        20 + // token
+        8 + // chain_id
+        16 + // amount (u128)
+        1 + // status
+        32 + // output_hash
+        8 + // created_slot
+        8 // completed_slot
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn open(
+        &mut self,
+        request_id: [u8; 32],
+        requester: Pubkey,
+        payer: Pubkey,
+        kind: RequestKind,
+        token: [u8; 20],
+        chain_id: u64,
+        amount: u128,
+        slot: u64,
+    ) {
+        self.request_id = request_id;
+        self.requester = requester;
+        self.payer = payer;
+        self.kind = kind;
+        self.token = token;
+        self.chain_id = chain_id;
+        self.amount = amount;
+        self.status = RequestStatus::Pending;
+        self.output_hash = [0u8; 32];
+        self.created_slot = slot;
+        self.completed_slot = 0;
+    }
+
+    /// Records the final outcome, `output` is the MPC response if one was delivered
+    pub fn settle(&mut self, status: RequestStatus, output: Option<&[u8]>) -> Result<()> {
+        require!(
+            self.status == RequestStatus::Pending,
+            crate::error::ErrorCode::RequestAlreadySettled
+        );
+
+        self.status = status;
+        if let Some(output) = output {
+            self.output_hash = keccak::hash(output).to_bytes();
+        }
+        self.completed_slot = Clock::get()?.slot;
+
+        Ok(())
+    }
+}
+
+/// What a request record is compressed into. The PDA stays allocated so the request id can
+/// never be opened again, which keeps its MPC response from being replayed
+#[account]
+pub struct RequestTombstone {
+    pub request_id: [u8; 32],
+    pub status: RequestStatus,
+}
+
+impl RequestTombstone {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // request_id
+        1 // status
+    }
+}
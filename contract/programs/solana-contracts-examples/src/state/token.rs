@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+
+use crate::state::rate_limit::WithdrawalRateLimit;
+
+/// Registry entry for an ERC20 token accepted by the vault on a given EVM chain
+#[account]
+pub struct TokenConfig {
+    /// EVM chain the token lives on
+    pub chain_id: u64,
+    /// ERC20 contract address
+    pub erc20_address: [u8; 20],
+    /// Whether deposits and withdrawals are accepted
+    pub enabled: bool,
+    /// Token decimals on the EVM side
+    pub decimals: u8,
+    /// Display symbol for indexers and clients
+    pub symbol: String,
+    /// Display name, also the name of the wrapped mint
+    pub name: String,
+    /// Off-chain metadata of the wrapped mint, empty for none
+    pub uri: String,
+    pub min_deposit: u128,
+    pub max_deposit: u128,
+    pub min_withdrawal: u128,
+    pub max_withdrawal: u128,
+    /// Protocol fee on deposits and withdrawals, in basis points of the gross amount
+    pub fee_bps: u16,
+    /// Flat minimum fee, zero for none
+    pub min_fee: u128,
+    /// Withdrawals allowed across all users per rolling window, zero for unlimited
+    pub withdrawal_limit: u128,
+    /// Withdrawals allowed per user per rolling window, zero for unlimited
+    pub user_withdrawal_limit: u128,
+    pub rate_limit_window_slots: u64,
+    /// ERC20 withdrawals above this are queued for the guardian to review, zero for none
+    pub large_withdrawal_threshold: u128,
+}
+
+impl TokenConfig {
+    pub const MAX_SYMBOL_LEN: usize = 16;
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_FEE_BPS: u16 = 10_000;
+    /// Wrapped mints never go above SOL's precision, an 18 decimal supply would overflow u64
+    pub const MAX_WRAPPED_DECIMALS: u8 = 9;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        8 + // chain_id
+        20 + // erc20_address
+        1 + // enabled
+        1 + // decimals
+        4 + Self::MAX_SYMBOL_LEN + // symbol
+        4 + Self::MAX_NAME_LEN + // name
+        4 + Self::MAX_URI_LEN + // uri
+        16 + // min_deposit
+        16 + // max_deposit
+        16 + // min_withdrawal
+        16 + // max_withdrawal
+        2 + // fee_bps
+        16 + // min_fee
+        16 + // withdrawal_limit
+        16 + // user_withdrawal_limit
+        8 + // rate_limit_window_slots
+        16 // large_withdrawal_threshold
+    }
+
+    /// Loads a registry entry, rejecting tokens that were never registered
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require!(
+            account.owner == &crate::ID && !account.data_is_empty(),
+            crate::error::ErrorCode::TokenNotRegistered
+        );
+
+        let data = account.try_borrow_data()?;
+        Self::try_deserialize(&mut &data[..])
+            .map_err(|_| crate::error::ErrorCode::TokenNotRegistered.into())
+    }
+
+    pub fn apply(&mut self, params: TokenConfigParams) -> Result<()> {
+        require!(
+            params.symbol.len() <= Self::MAX_SYMBOL_LEN
+                && params.name.len() <= Self::MAX_NAME_LEN
+                && params.uri.len() <= Self::MAX_URI_LEN,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.min_deposit <= params.max_deposit
+                && params.min_withdrawal <= params.max_withdrawal,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.fee_bps <= Self::MAX_FEE_BPS,
+            crate::error::ErrorCode::InvalidConfig
+        );
+        require!(
+            params.rate_limit_window_slots > 0
+                || (params.withdrawal_limit == 0 && params.user_withdrawal_limit == 0),
+            crate::error::ErrorCode::InvalidConfig
+        );
+
+        self.enabled = params.enabled;
+        self.decimals = params.decimals;
+        self.symbol = params.symbol;
+        self.name = params.name;
+        self.uri = params.uri;
+        self.min_deposit = params.min_deposit;
+        self.max_deposit = params.max_deposit;
+        self.min_withdrawal = params.min_withdrawal;
+        self.max_withdrawal = params.max_withdrawal;
+        self.fee_bps = params.fee_bps;
+        self.min_fee = params.min_fee;
+        self.withdrawal_limit = params.withdrawal_limit;
+        self.user_withdrawal_limit = params.user_withdrawal_limit;
+        self.rate_limit_window_slots = params.rate_limit_window_slots;
+        self.large_withdrawal_threshold = params.large_withdrawal_threshold;
+
+        Ok(())
+    }
+
+    pub fn require_deposit_allowed(&self, amount: u128) -> Result<()> {
+        require!(self.enabled, crate::error::ErrorCode::TokenDisabled);
+        require!(
+            amount >= self.min_deposit,
+            crate::error::ErrorCode::AmountBelowMinimum
+        );
+        require!(
+            amount <= self.max_deposit,
+            crate::error::ErrorCode::AmountAboveMaximum
+        );
+
+        Ok(())
+    }
+
+    /// Protocol fee on a gross `amount`, which has to exceed it
+    pub fn fee_for(&self, amount: u128) -> Result<u128> {
+        let fee = amount
+            .checked_mul(self.fee_bps as u128)
+            .ok_or(crate::error::ErrorCode::Overflow)?
+            / 10_000;
+        let fee = fee.max(self.min_fee);
+
+        require!(
+            fee == 0 || fee < amount,
+            crate::error::ErrorCode::AmountBelowFee
+        );
+
+        Ok(fee)
+    }
+
+    pub fn require_withdrawal_allowed(&self, amount: u128) -> Result<()> {
+        require!(self.enabled, crate::error::ErrorCode::TokenDisabled);
+        require!(
+            amount >= self.min_withdrawal,
+            crate::error::ErrorCode::AmountBelowMinimum
+        );
+        require!(
+            amount <= self.max_withdrawal,
+            crate::error::ErrorCode::AmountAboveMaximum
+        );
+
+        Ok(())
+    }
+
+    /// Whether a withdrawal of `amount` has to wait out the large-withdrawal delay
+    pub fn requires_delay(&self, amount: u128) -> bool {
+        self.large_withdrawal_threshold > 0 && amount > self.large_withdrawal_threshold
+    }
+
+    /// Decimals of the token's wrapped mint
+    pub fn wrapped_decimals(&self) -> u8 {
+        self.decimals.min(Self::MAX_WRAPPED_DECIMALS)
+    }
+
+    /// Converts a balance amount into units of a wrapped mint with `mint_decimals`, rejecting
+    /// amounts with dust below one wrapped unit
+    pub fn to_wrapped_amount(&self, amount: u128, mint_decimals: u8) -> Result<u64> {
+        let (wrapped, dust) = self.split_wrapped_amount(amount, mint_decimals)?;
+        require!(dust == 0, crate::error::ErrorCode::WrappedAmountNotExact);
+        Ok(wrapped)
+    }
+
+    /// Splits a balance amount into whole units of a wrapped mint with `mint_decimals` and the
+    /// dust below one unit, which stays in the token's EVM units
+    pub fn split_wrapped_amount(&self, amount: u128, mint_decimals: u8) -> Result<(u64, u128)> {
+        let scale_exponent = self
+            .decimals
+            .checked_sub(mint_decimals)
+            .ok_or(crate::error::ErrorCode::InvalidConfig)?;
+        let scale = 10u128
+            .checked_pow(u32::from(scale_exponent))
+            .ok_or(crate::error::ErrorCode::Overflow)?;
+        let wrapped =
+            u64::try_from(amount / scale).map_err(|_| crate::error::ErrorCode::Overflow)?;
+        Ok((wrapped, amount % scale))
+    }
+
+    /// Counts a withdrawal against both the vault-wide and the requester's rolling limit
+    pub fn consume_withdrawal_limits(
+        &self,
+        token_usage: &mut WithdrawalRateLimit,
+        user_usage: &mut WithdrawalRateLimit,
+        amount: u128,
+    ) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        token_usage.consume(
+            amount,
+            self.withdrawal_limit,
+            self.rate_limit_window_slots,
+            slot,
+        )?;
+        user_usage.consume(
+            amount,
+            self.user_withdrawal_limit,
+            self.rate_limit_window_slots,
+            slot,
+        )
+    }
+}
+
+/// Settable fields of a token registry entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenConfigParams {
+    pub enabled: bool,
+    pub decimals: u8,
+    pub symbol: String,
+    pub name: String,
+    pub uri: String,
+    pub min_deposit: u128,
+    pub max_deposit: u128,
+    pub min_withdrawal: u128,
+    pub max_withdrawal: u128,
+    pub fee_bps: u16,
+    pub min_fee: u128,
+    pub withdrawal_limit: u128,
+    pub user_withdrawal_limit: u128,
+    pub rate_limit_window_slots: u64,
+    pub large_withdrawal_threshold: u128,
+}
@@ -21,49 +21,189 @@ sol! {
 // PDA for storing pending ERC20 deposits
 #[account]
 pub struct PendingErc20Deposit {
+    /// Layout version, see `LAYOUT_VERSION`
+    pub version: u8,
     pub requester: Pubkey,
     pub amount: u128,
     pub erc20_address: [u8; 20],
+    pub chain_id: u64,
     pub path: String,
     pub request_id: [u8; 32],
+    pub key_version: u32,
+    /// Account that paid the rent for this record
+    pub payer: Pubkey,
+    /// Lamports escrowed for whoever submits the response
+    pub relayer_tip: u64,
+    pub created_slot: u64,
+    /// Slot after which the deposit can be cancelled if no response arrived
+    pub expires_at_slot: u64,
+    /// Parameters of the sweep transaction that was sent for signing
+    pub tx_params: EvmTransactionParams,
+    /// keccak256 of the unsigned payload, the hash the MPC network signs
+    pub payload_hash: [u8; 32],
+    /// Protocol fee withheld from `amount` when the deposit is claimed
+    pub fee: u128,
+    /// Whether the claim mints wrapped tokens instead of crediting the balance
+    pub wrapped: bool,
 }
 
 impl PendingErc20Deposit {
+    /// Bumped whenever fields are added, 1 added `tx_params` and `payload_hash`, 2 added `fee`,
+    /// 3 added `wrapped`
+    pub const LAYOUT_VERSION: u8 = 3;
     pub const MAX_PATH_LEN: usize = 64;
 
     pub fn space() -> usize {
         8 + // discriminator
+        1 + // version
         32 + // requester
         16 + // amount (u128)
         20 + // erc20_address
+        8 + // chain_id
         4 + Self::MAX_PATH_LEN + // path string
-        32 // request_id
+        32 + // request_id
+        4 + // key_version
+        32 + // payer
+        8 + // relayer_tip
+        8 + // created_slot
+        8 + // expires_at_slot
+        EvmTransactionParams::SIZE + // tx_params
+        32 + // payload_hash
+        16 + // fee
+        1 // wrapped
     }
 }
 
 #[account]
 pub struct PendingErc20Withdrawal {
+    /// Layout version, see `LAYOUT_VERSION`
+    pub version: u8,
     pub requester: Pubkey,
     pub amount: u128,
     pub erc20_address: [u8; 20],
     pub recipient_address: [u8; 20],
+    /// Chain the withdrawal is executed on
+    pub chain_id: u64,
+    /// Chain whose balance was debited, differs from `chain_id` only for fungible chains
+    pub source_chain_id: u64,
     pub path: String,
     pub request_id: [u8; 32],
+    pub key_version: u32,
+    /// Account that paid the rent for this record
+    pub payer: Pubkey,
+    /// Lamports escrowed for whoever submits the response
+    pub relayer_tip: u64,
+    pub created_slot: u64,
+    /// Slot after which the balance can be restored if no response arrived
+    pub refundable_at_slot: u64,
+    /// Unsigned EIP-1559 payload sent to `sign_respond`
+    pub rlp_encoded_tx: Vec<u8>,
+    /// Hash of the last signed EVM transaction recorded, zero until one is
+    pub evm_tx_hash: [u8; 32],
+    /// Parameters of the latest transfer that was sent for signing
+    pub tx_params: EvmTransactionParams,
+    /// keccak256 of the latest unsigned payload, the hash the MPC network signs
+    pub payload_hash: [u8; 32],
+    /// Fee-bumped replacements of `request_id`, they share its nonce so at most one can land
+    pub replacement_request_ids: Vec<[u8; 32]>,
+    /// Bit 0 is set once `signed_request_id` responded, bit i once replacement i - 1 did
+    pub responded: u8,
+    /// Protocol fee kept out of the transfer, `amount - fee` is sent to the recipient
+    pub fee: u128,
+    /// Slot a queued withdrawal can be executed from, zero once it was sent for signing
+    pub executable_at_slot: u64,
+    /// Request first sent to `sign_respond`, `request_id` unless the withdrawal was queued
+    pub signed_request_id: [u8; 32],
+    /// Parameters of the transactions superseded by fee bumps, in the order they were sent
+    pub previous_tx_params: Vec<EvmTransactionParams>,
+    /// Response bits, as in `responded`, of the transactions whose signature was recorded
+    pub recorded: u8,
+    /// EVM address the transfer is sent from, the address derived under `path` when the
+    /// withdrawal was sent for signing
+    pub signer_address: [u8; 20],
 }
 
 impl PendingErc20Withdrawal {
+    /// Bumped whenever fields are added, 1 added `tx_params` and `payload_hash`,
+    /// 2 added fee-bump replacements, 3 added `fee`, 4 added the large-withdrawal queue,
+    /// 5 added per-transaction signature recording
+    pub const LAYOUT_VERSION: u8 = 5;
+    pub const MAX_FEE_BUMPS: usize = 3;
+
     pub const fn space() -> usize {
         8 +  // discriminator
+        1 + // version
         32 + // requester
         16 + // amount (u128)
         20 + // erc20_address
         20 + // recipient_address
+        8 + // chain_id
+        8 + // source_chain_id
         4 + 64 + // path (string with max length)
-        32 // request_id
+        32 + // request_id
+        4 + // key_version
+        32 + // payer
+        8 + // relayer_tip
+        8 + // created_slot
+        8 + // refundable_at_slot
+        4 + crate::evm::MAX_UNSIGNED_TX_LEN + // rlp_encoded_tx
+        32 + // evm_tx_hash
+        EvmTransactionParams::SIZE + // tx_params
+        32 + // payload_hash
+        4 + 32 * Self::MAX_FEE_BUMPS + // replacement_request_ids
+        1 + // responded
+        16 + // fee
+        8 + // executable_at_slot
+        32 + // signed_request_id
+        4 + EvmTransactionParams::SIZE * Self::MAX_FEE_BUMPS + // previous_tx_params
+        1 + // recorded
+        20 // signer_address
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.executable_at_slot != 0
+    }
+
+    /// Amount the EVM transfer sends, the fee stays with the vault
+    pub fn net_amount(&self) -> Result<u128> {
+        self.amount
+            .checked_sub(self.fee)
+            .ok_or(crate::error::ErrorCode::Underflow.into())
+    }
+
+    /// Response bit of `request_id`, which must be the signed request or one of its replacements
+    pub fn response_bit(&self, request_id: &[u8; 32]) -> Result<u8> {
+        require!(!self.is_queued(), crate::error::ErrorCode::WithdrawalQueued);
+
+        if *request_id == self.signed_request_id {
+            return Ok(1);
+        }
+
+        let index = self
+            .replacement_request_ids
+            .iter()
+            .position(|id| id == request_id)
+            .ok_or(crate::error::ErrorCode::InvalidRequestId)?;
+
+        Ok(1 << (index + 1))
+    }
+
+    /// Parameters of the transaction sent as `request_id`, the signed request or a replacement
+    pub fn tx_params_of(&self, request_id: &[u8; 32]) -> Result<&EvmTransactionParams> {
+        let index = self.response_bit(request_id)?.trailing_zeros() as usize;
+        Ok(self
+            .previous_tx_params
+            .get(index)
+            .unwrap_or(&self.tx_params))
+    }
+
+    /// Whether every linked request has responded, only then can a failure be refunded
+    pub fn all_responded(&self) -> bool {
+        self.responded.count_ones() as usize == 1 + self.replacement_request_ids.len()
     }
 }
 
-// PDA for storing user ERC20 balances
+// PDA for storing user ERC20 balances, keyed by the EVM chain they were deposited on
 #[account]
 pub struct UserErc20Balance {
     pub amount: u128,
@@ -76,6 +216,99 @@ impl UserErc20Balance {
     }
 }
 
+// PDA for storing pending native ETH deposits
+#[account]
+pub struct PendingNativeDeposit {
+    pub requester: Pubkey,
+    pub amount: u128,
+    pub chain_id: u64,
+    pub path: String,
+    pub request_id: [u8; 32],
+    pub key_version: u32,
+    /// Account that paid the rent for this record
+    pub payer: Pubkey,
+    /// Lamports escrowed for whoever submits the response
+    pub relayer_tip: u64,
+    pub created_slot: u64,
+    /// Slot after which the deposit can be cancelled if no response arrived
+    pub expires_at_slot: u64,
+}
+
+impl PendingNativeDeposit {
+    pub const MAX_PATH_LEN: usize = 64;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // requester
+        16 + // amount (u128)
+        8 + // chain_id
+        4 + Self::MAX_PATH_LEN + // path string
+        32 + // request_id
+        4 + // key_version
+        32 + // payer
+        8 + // relayer_tip
+        8 + // created_slot
+        8 // expires_at_slot
+    }
+}
+
+#[account]
+pub struct PendingNativeWithdrawal {
+    pub requester: Pubkey,
+    pub amount: u128,
+    pub recipient_address: [u8; 20],
+    pub chain_id: u64,
+    pub path: String,
+    pub request_id: [u8; 32],
+    pub key_version: u32,
+    /// Account that paid the rent for this record
+    pub payer: Pubkey,
+    /// Lamports escrowed for whoever submits the response
+    pub relayer_tip: u64,
+    pub created_slot: u64,
+    /// Slot after which the balance can be restored if no response arrived
+    pub refundable_at_slot: u64,
+    /// Unsigned EIP-1559 payload sent to `sign_respond`
+    pub rlp_encoded_tx: Vec<u8>,
+    /// Hash of the signed EVM transaction, zero until the MPC signature is recorded
+    pub evm_tx_hash: [u8; 32],
+    /// EVM address the transfer is sent from, the address derived under `path`
+    pub signer_address: [u8; 20],
+}
+
+impl PendingNativeWithdrawal {
+    pub const fn space() -> usize {
+        8 +  // discriminator
+        32 + // requester
+        16 + // amount (u128)
+        20 + // recipient_address
+        8 + // chain_id
+        4 + 64 + // path (string with max length)
+        32 + // request_id
+        4 + // key_version
+        32 + // payer
+        8 + // relayer_tip
+        8 + // created_slot
+        8 + // refundable_at_slot
+        4 + crate::evm::MAX_UNSIGNED_TX_LEN + // rlp_encoded_tx
+        32 + // evm_tx_hash
+        20 // signer_address
+    }
+}
+
+// PDA for storing user native ETH balances, keyed by EVM chain
+#[account]
+pub struct UserNativeBalance {
+    pub amount: u128,
+}
+
+impl UserNativeBalance {
+    pub fn space() -> usize {
+        8 + // discriminator
+        16 // amount (u128)
+    }
+}
+
 // Transaction parameters for EVM
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct EvmTransactionParams {
@@ -87,6 +320,27 @@ pub struct EvmTransactionParams {
     pub chain_id: u64,
 }
 
+impl EvmTransactionParams {
+    pub const SIZE: usize = 16 + // value
+        16 + // gas_limit
+        16 + // max_fee_per_gas
+        16 + // max_priority_fee_per_gas
+        8 + // nonce
+        8; // chain_id
+}
+
+/// What a deposit or withdrawal would submit to `sign_respond`, returned by the preview instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignRequestPreview {
+    /// Unsigned RLP-encoded EVM transaction
+    pub rlp_encoded_tx: Vec<u8>,
+    /// keccak256 of the RLP payload, the hash the MPC network signs
+    pub signing_hash: [u8; 32],
+    pub request_id: [u8; 32],
+    /// Derivation path the request is signed under
+    pub path: String,
+}
+
 /// Represents a vault transaction to be processed
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct VaultTransaction {
@@ -164,6 +418,10 @@ pub struct SignVaultTransaction<'info> {
     /// The user authority that owns this vault
     pub authority: Signer<'info>,
 
+    /// Vault config holding the pause flags
+    #[account(seeds = [b"vault_config"], bump)]
+    pub config: Account<'info, crate::state::config::VaultConfig>,
+
     /// User-specific vault authority PDA that acts as the requester
     #[account(
         mut,
@@ -186,12 +444,13 @@ pub struct SignVaultTransaction<'info> {
     )]
     pub chain_signatures_state: AccountInfo<'info>,
 
-    /// The chain signatures program
+    /// CHECK: Must match the chain signatures program recorded in the vault config
     #[account(
-        constraint = chain_signatures_program.key().to_string() == crate::constants::CHAIN_SIGNATURES_PROGRAM_ID
+        executable,
+        address = config.chain_signatures_program
             @ crate::error::ErrorCode::InvalidChainSignaturesProgram
     )]
-    pub chain_signatures_program: Program<'info, chain_signatures::program::ChainSignaturesProject>,
+    pub chain_signatures_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     pub instructions: Option<AccountInfo<'info>>,
 }
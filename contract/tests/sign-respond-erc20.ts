@@ -14,6 +14,14 @@ const CONFIG = {
   // Contract Addresses
   USDC_ADDRESS_SEPOLIA: "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238",
   HARDCODED_RECIPIENT: "0xdcF0f02E13eF171aA028Bc7d4c452CFCe3C2E18f",
+  MPC_ROOT_SIGNER_ADDRESS: "0x00A40C2661293d5134E53Da52951A3F7767836Ef",
+  WITHDRAWAL_ROOT_PATH: "root",
+  MPC_KEY_VERSION: 0,
+  MOCK_RESPONDER_SEED: "vault failed withdrawal responder",
+  KEY_ROTATION_GRACE_PERIOD: 24 * 60 * 60,
+  DEPOSIT_TIMEOUT_SLOTS: 216_000, // ~1 day
+  WITHDRAWAL_REFUND_DELAY_SLOTS: 1_512_000, // ~7 days
+  LARGE_WITHDRAWAL_DELAY_SLOTS: 216_000, // ~1 day
 
   // Chain Configuration
   SEPOLIA_CHAIN_ID: 11155111,
@@ -32,6 +40,8 @@ const CONFIG = {
   TRANSFER_AMOUNT: "0.1", // USDC
   DECIMALS: 6,
   GAS_BUFFER_PERCENT: 20,
+  MAX_GAS_LIMIT: 500_000,
+  MAX_FEE_PER_GAS: "500", // gwei
 
   // Solana Programs
   CHAIN_SIGNATURES_PROGRAM_ID: "4uvZW8K4g4jBg7dzPNbb9XDxJLFBK7V6iC76uofmYvEU",
@@ -305,7 +315,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
   let chainSignaturesProgram: Program<ChainSignaturesProject>;
   let ethUtils: EthereumUtils;
 
-  before(() => {
+  before(async () => {
     // Setup Anchor provider
     provider = anchor.AnchorProvider.env();
     anchor.setProvider(provider);
@@ -317,6 +327,14 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
 
     // Initialize Ethereum utilities
     ethUtils = new EthereumUtils();
+
+    await ensureVaultConfig(program);
+    await ensureTokenRegistered(
+      program,
+      CONFIG.SEPOLIA_CHAIN_ID,
+      CONFIG.USDC_ADDRESS_SEPOLIA
+    );
+    await ensureChainConfigured(program, CONFIG.SEPOLIA_CHAIN_ID);
   });
 
   it("Should complete full ERC20 deposit flow", async function () {
@@ -421,11 +439,15 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
         provider.wallet.publicKey,
         erc20AddressBytes as any,
         amountBN,
-        txParams
+        txParams,
+        new anchor.BN(0)
       )
       .accounts({
         payer: provider.wallet.publicKey,
+        userBalance: accounts.userBalance,
+        wrappedMint: null,
         feePayer: provider.wallet.publicKey,
+        chainSignaturesProgram: CONFIG.CHAIN_SIGNATURES_PROGRAM_ID,
         instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       })
       .rpc();
@@ -480,6 +502,14 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       )
       .accounts({
         userBalance: accounts.userBalance,
+        // The test token is registered without a protocol fee
+        feeBalance: null,
+        tokenConfig: null,
+        wrappedMint: null,
+        wrappedTokenAccount: null,
+        tokenProgram: null,
+        mpcSigner: getMpcSigner(program, CONFIG.MPC_KEY_VERSION),
+        rentPayer: provider.wallet.publicKey,
       })
       .rpc();
 
@@ -526,6 +556,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       [
         Buffer.from("user_erc20_balance"),
         provider.wallet.publicKey.toBuffer(),
+        chainIdSeed(CONFIG.SEPOLIA_CHAIN_ID),
         Buffer.from(erc20AddressBytes),
       ],
       program.programId
@@ -641,7 +672,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       Array.from(ethers.getBytes(rlpEncodedTx)),
       CONFIG.ETHEREUM_SLIP44,
       0,
-      "root", // HARDCODED_ROOT_PATH
+      CONFIG.WITHDRAWAL_ROOT_PATH,
       "ECDSA",
       "ethereum",
       ""
@@ -673,11 +704,20 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
         erc20AddressBytes as any,
         withdrawAmount,
         recipientAddressBytes as any,
-        txParams
+        txParams,
+        new anchor.BN(CONFIG.SEPOLIA_CHAIN_ID),
+        new anchor.BN(0)
       )
       .accounts({
         authority: provider.wallet.publicKey,
+        owner: provider.wallet.publicKey,
+        allowance: null,
+        wrappedMint: null,
+        wrappedTokenAccount: null,
+        tokenProgram: null,
         feePayer: provider.wallet.publicKey,
+        nonceTracker: await syncNonceTracker(program, nonce),
+        chainSignaturesProgram: CONFIG.CHAIN_SIGNATURES_PROGRAM_ID,
         instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
       })
       .rpc();
@@ -752,7 +792,15 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
         readEvent.signature
       )
       .accounts({
+        ...getWithdrawalAccounts(program, requestIdBytes),
         userBalance,
+        feeBalance: null,
+        tokenConfig: null,
+        wrappedMint: null,
+        wrappedTokenAccount: null,
+        tokenProgram: null,
+        mpcSigner: getMpcSigner(program, CONFIG.MPC_KEY_VERSION),
+        rentPayer: provider.wallet.publicKey,
       })
       .rpc();
 
@@ -829,6 +877,7 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
       [
         Buffer.from("user_erc20_balance"),
         provider.wallet.publicKey.toBuffer(),
+        chainIdSeed(CONFIG.SEPOLIA_CHAIN_ID),
         Buffer.from(erc20AddressBytes),
       ],
       program.programId
@@ -845,233 +894,479 @@ describe("🏦 ERC20 Deposit, Withdraw and Withdraw with refund Flow", () => {
     }
 
     // =====================================================
-    // STEP 2: CREATE FAILING WITHDRAWAL
+    // STEP 2: SWITCH TO A MOCK RESPONDER KEY
     // =====================================================
 
-    console.log("\n📍 Step 2: Creating withdrawal that will fail...");
+    // The MPC network only answers for transactions it sees on Sepolia, so the failure
+    // response comes from a local key the vault is rotated to for this test
+    console.log("\n📍 Step 2: Rotating to a mock responder key...");
 
-    const recipientAddress = "0x0000000000000000000000000000000000000001";
-    const recipientAddressBytes = Array.from(
-      Buffer.from(recipientAddress.slice(2), "hex")
+    const mockResponder = new ethers.Wallet(
+      ethers.keccak256(ethers.toUtf8Bytes(CONFIG.MOCK_RESPONDER_SEED))
     );
+    const mockKeyVersion = await registerMockResponder(program, mockResponder);
+    console.log("  🔑 Mock responder key version:", mockKeyVersion);
 
-    const withdrawAmount = currentBalance.amount;
-
-    // Get current nonce
-    const ethprovider = ethUtils.getProvider();
-    const currentNonce = await ethprovider.getTransactionCount(
-      CONFIG.HARDCODED_RECIPIENT
-    );
-    console.log("  📊 Current nonce:", currentNonce);
-
-    // Use an old nonce to make transaction fail
-    const oldNonce = currentNonce > 0 ? currentNonce - 1 : 0;
-    console.log("  📊 Using already-used nonce:", oldNonce);
-
-    // Build withdrawal transaction with OLD nonce
-    const transferInterface = new ethers.Interface([
-      "function transfer(address to, uint256 amount) returns (bool)",
-    ]);
-    const callData = transferInterface.encodeFunctionData("transfer", [
-      recipientAddress,
-      withdrawAmount.toString(),
-    ]);
-
-    const feeData = await ethprovider.getFeeData();
-    const maxFeePerGas =
-      feeData.maxFeePerGas || ethers.parseUnits("30", "gwei");
-    const maxPriorityFeePerGas =
-      feeData.maxPriorityFeePerGas || ethers.parseUnits("2", "gwei");
-
-    const gasEstimate = 100000;
-
-    const txParams: TransactionParams = {
-      nonce: new anchor.BN(oldNonce), // OLD NONCE
-      value: new anchor.BN(0),
-      maxPriorityFeePerGas: new anchor.BN(maxPriorityFeePerGas.toString()),
-      maxFeePerGas: new anchor.BN(maxFeePerGas.toString()),
-      gasLimit: new anchor.BN(gasEstimate.toString()),
-      chainId: new anchor.BN(CONFIG.SEPOLIA_CHAIN_ID),
-    };
-
-    const tempTx = {
-      type: 2,
-      chainId: CONFIG.SEPOLIA_CHAIN_ID,
-      nonce: oldNonce,
-      maxPriorityFeePerGas,
-      maxFeePerGas,
-      gasLimit: BigInt(gasEstimate),
-      to: CONFIG.USDC_ADDRESS_SEPOLIA,
-      value: BigInt(0),
-      data: callData,
-    };
+    try {
+      // =====================================================
+      // STEP 3: INITIATE WITHDRAWAL
+      // =====================================================
 
-    const rlpEncodedTx = ethers.Transaction.from(tempTx).unsignedSerialized;
+      console.log("\n📍 Step 3: Initiating withdrawal...");
 
-    const [globalVaultAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("global_vault_authority")],
-      program.programId
-    );
+      const recipientAddress = "0x0000000000000000000000000000000000000001";
+      const recipientAddressBytes = Array.from(
+        Buffer.from(recipientAddress.slice(2), "hex")
+      );
 
-    const requestId = CryptoUtils.generateSignRespondRequestId(
-      globalVaultAuthority.toString(),
-      Array.from(ethers.getBytes(rlpEncodedTx)),
-      CONFIG.ETHEREUM_SLIP44,
-      0,
-      "root", // HARDCODED_ROOT_PATH
-      "ECDSA",
-      "ethereum",
-      ""
-    );
-    const requestIdBytes = Array.from(Buffer.from(requestId.slice(2), "hex"));
+      const withdrawAmount = currentBalance.amount;
+
+      const transferInterface = new ethers.Interface([
+        "function transfer(address to, uint256 amount) returns (bool)",
+      ]);
+      const callData = transferInterface.encodeFunctionData("transfer", [
+        recipientAddress,
+        withdrawAmount.toString(),
+      ]);
+
+      // The mock key's withdrawal address has never sent anything
+      const nonce = 0;
+      const maxFeePerGas = ethers.parseUnits("30", "gwei");
+      const maxPriorityFeePerGas = ethers.parseUnits("2", "gwei");
+      const gasEstimate = 100000;
+
+      const txParams: TransactionParams = {
+        nonce: new anchor.BN(nonce),
+        value: new anchor.BN(0),
+        maxPriorityFeePerGas: new anchor.BN(maxPriorityFeePerGas.toString()),
+        maxFeePerGas: new anchor.BN(maxFeePerGas.toString()),
+        gasLimit: new anchor.BN(gasEstimate.toString()),
+        chainId: new anchor.BN(CONFIG.SEPOLIA_CHAIN_ID),
+      };
+
+      const rlpEncodedTx = ethers.Transaction.from({
+        type: 2,
+        chainId: CONFIG.SEPOLIA_CHAIN_ID,
+        nonce,
+        maxPriorityFeePerGas,
+        maxFeePerGas,
+        gasLimit: BigInt(gasEstimate),
+        to: CONFIG.USDC_ADDRESS_SEPOLIA,
+        value: BigInt(0),
+        data: callData,
+      }).unsignedSerialized;
+
+      const [globalVaultAuthority] =
+        anchor.web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("global_vault_authority")],
+          program.programId
+        );
 
-    // =====================================================
-    // STEP 3: SETUP EVENT LISTENERS
-    // =====================================================
+      const requestId = CryptoUtils.generateSignRespondRequestId(
+        globalVaultAuthority.toString(),
+        Array.from(ethers.getBytes(rlpEncodedTx)),
+        CONFIG.ETHEREUM_SLIP44,
+        mockKeyVersion,
+        CONFIG.WITHDRAWAL_ROOT_PATH,
+        "ECDSA",
+        "ethereum",
+        ""
+      );
+      const requestIdBytes = Array.from(Buffer.from(requestId.slice(2), "hex"));
+
+      const balanceBeforeWithdraw = currentBalance.amount;
+
+      const withdrawTx = await program.methods
+        .withdrawErc20(
+          requestIdBytes as any,
+          erc20AddressBytes as any,
+          withdrawAmount,
+          recipientAddressBytes as any,
+          txParams,
+          new anchor.BN(CONFIG.SEPOLIA_CHAIN_ID),
+          new anchor.BN(0)
+        )
+        .accounts({
+          authority: provider.wallet.publicKey,
+          owner: provider.wallet.publicKey,
+          allowance: null,
+          wrappedMint: null,
+          wrappedTokenAccount: null,
+          tokenProgram: null,
+          feePayer: provider.wallet.publicKey,
+          currentSigner: getMpcSigner(program, mockKeyVersion),
+          nonceTracker: await syncNonceTracker(program, nonce, mockKeyVersion),
+          chainSignaturesProgram: CONFIG.CHAIN_SIGNATURES_PROGRAM_ID,
+          instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .rpc();
+
+      console.log("  ✅ Withdrawal transaction:", withdrawTx);
+
+      // Check balance was decremented optimistically
+      const balanceAfterWithdraw =
+        await program.account.userErc20Balance.fetch(userBalance);
+      console.log(
+        "  💰 Balance after withdrawal:",
+        balanceAfterWithdraw.amount.toString()
+      );
+      expect(balanceAfterWithdraw.amount.toString()).to.equal("0");
+
+      // =====================================================
+      // STEP 4: COMPLETE WITH A FAILED RESPONSE (REFUND)
+      // =====================================================
+
+      console.log("\n📍 Step 4: Completing withdrawal with a failed response...");
+
+      // Reverted transactions are answered with the error prefix and the revert reason
+      const serializedOutput = Buffer.concat([
+        Buffer.from("deadbeef", "hex"),
+        Buffer.from("execution reverted"),
+      ]);
+
+      const completeTx = await program.methods
+        .completeWithdrawErc20(
+          requestIdBytes as any,
+          serializedOutput,
+          signMockResponse(mockResponder, requestId, serializedOutput)
+        )
+        .accounts({
+          ...getWithdrawalAccounts(program, requestIdBytes),
+          userBalance,
+          feeBalance: null,
+          tokenConfig: null,
+          wrappedMint: null,
+          wrappedTokenAccount: null,
+          tokenProgram: null,
+          mpcSigner: getMpcSigner(program, mockKeyVersion),
+          rentPayer: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      console.log("  ✅ Complete withdrawal transaction:", completeTx);
+
+      // =====================================================
+      // STEP 5: VERIFY REFUND
+      // =====================================================
+
+      console.log("\n📍 Step 5: Verifying balance was refunded...");
+
+      const finalBalance = await program.account.userErc20Balance.fetch(
+        userBalance
+      );
 
-    console.log("\n📍 Step 3: Setting up event listeners...");
+      console.log("  💰 Initial balance:", balanceBeforeWithdraw.toString());
+      console.log("  💰 Final balance:", finalBalance.amount.toString());
 
-    const eventPromises = setupEventListeners(
-      chainSignaturesProgram,
-      requestId,
-      CONFIG.HARDCODED_RECIPIENT,
-      rlpEncodedTx
-    );
+      expect(finalBalance.amount.toString()).to.equal(
+        balanceBeforeWithdraw.toString()
+      );
+    } finally {
+      await restoreMpcKey(program, mockKeyVersion);
+    }
 
-    // =====================================================
-    // STEP 4: INITIATE WITHDRAWAL
-    // =====================================================
+    console.log("\n🎉 Failed withdrawal handled correctly - balance refunded!");
+  });
+});
 
-    console.log("\n📍 Step 4: Initiating withdrawal...");
+/**
+ * Initialize the vault config on first run
+ */
+async function ensureVaultConfig(program: Program<SolanaCoreContracts>) {
+  const [config] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault_config")],
+    program.programId
+  );
 
-    const balanceBeforeWithdraw = currentBalance.amount;
+  const existing = await program.provider.connection.getAccountInfo(config);
+  if (existing) {
+    return;
+  }
 
-    const withdrawTx = await program.methods
-      .withdrawErc20(
-        requestIdBytes as any,
-        erc20AddressBytes as any,
-        withdrawAmount,
-        recipientAddressBytes as any,
-        txParams
-      )
-      .accounts({
-        authority: provider.wallet.publicKey,
-        feePayer: provider.wallet.publicKey,
-        instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
-      })
-      .rpc();
+  await program.methods
+    .initializeConfig({
+      guardian: program.provider.publicKey,
+      sweepRecipient: Array.from(
+        Buffer.from(CONFIG.HARDCODED_RECIPIENT.slice(2), "hex")
+      ),
+      withdrawalRootPath: CONFIG.WITHDRAWAL_ROOT_PATH,
+      chainSignaturesProgram: new anchor.web3.PublicKey(
+        CONFIG.CHAIN_SIGNATURES_PROGRAM_ID
+      ),
+      keyRotationGracePeriod: new anchor.BN(CONFIG.KEY_ROTATION_GRACE_PERIOD),
+      fungibleChainIds: [],
+      depositTimeoutSlots: new anchor.BN(CONFIG.DEPOSIT_TIMEOUT_SLOTS),
+      withdrawalRefundDelaySlots: new anchor.BN(
+        CONFIG.WITHDRAWAL_REFUND_DELAY_SLOTS
+      ),
+      feeAuthority: program.provider.publicKey,
+      largeWithdrawalDelaySlots: new anchor.BN(
+        CONFIG.LARGE_WITHDRAWAL_DELAY_SLOTS
+      ),
+    })
+    .accounts({
+      // Only the program's upgrade authority may initialize the config
+      programData: anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      )[0],
+    })
+    .rpc();
+
+  await program.methods
+    .registerMpcSigner(
+      CONFIG.MPC_KEY_VERSION,
+      Array.from(Buffer.from(CONFIG.MPC_ROOT_SIGNER_ADDRESS.slice(2), "hex")),
+      // Withdrawals are sent from the withdrawal root path's address, the sweep recipient
+      Array.from(Buffer.from(CONFIG.HARDCODED_RECIPIENT.slice(2), "hex"))
+    )
+    .rpc();
+
+  console.log("  ✅ Vault config initialized");
+}
 
-    console.log("  ✅ Withdrawal transaction:", withdrawTx);
+/**
+ * Register a token with the vault registry on first run
+ */
+async function ensureTokenRegistered(
+  program: Program<SolanaCoreContracts>,
+  chainId: number,
+  erc20Address: string
+) {
+  const erc20AddressBytes = Array.from(
+    Buffer.from(erc20Address.slice(2), "hex")
+  );
+  const [tokenConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("token_config"),
+      chainIdSeed(chainId),
+      Buffer.from(erc20AddressBytes),
+    ],
+    program.programId
+  );
 
-    // Check balance was decremented optimistically
-    const balanceAfterWithdraw = await program.account.userErc20Balance.fetch(
-      userBalance
-    );
-    console.log(
-      "  💰 Balance after withdrawal:",
-      balanceAfterWithdraw.amount.toString()
-    );
-    expect(balanceAfterWithdraw.amount.toString()).to.equal("0");
+  const existing = await program.provider.connection.getAccountInfo(
+    tokenConfig
+  );
+  if (existing) {
+    return;
+  }
 
-    // =====================================================
-    // STEP 5: WAIT FOR SIGNATURE
-    // =====================================================
+  const maxAmount = new anchor.BN(2).pow(new anchor.BN(128)).subn(1);
+
+  await program.methods
+    .registerToken(new anchor.BN(chainId), erc20AddressBytes as any, {
+      enabled: true,
+      decimals: CONFIG.DECIMALS,
+      symbol: "USDC",
+      name: "USD Coin",
+      uri: "",
+      minDeposit: new anchor.BN(0),
+      maxDeposit: maxAmount,
+      minWithdrawal: new anchor.BN(0),
+      maxWithdrawal: maxAmount,
+      feeBps: 0,
+      minFee: new anchor.BN(0),
+      withdrawalLimit: new anchor.BN(0),
+      userWithdrawalLimit: new anchor.BN(0),
+      rateLimitWindowSlots: new anchor.BN(0),
+      largeWithdrawalThreshold: new anchor.BN(0),
+    })
+    .rpc();
+
+  console.log("  ✅ Token registered:", erc20Address);
+}
 
-    console.log("\n📍 Step 5: Waiting for signature...");
+/**
+ * Set the gas caps of a chain on first run
+ */
+async function ensureChainConfigured(
+  program: Program<SolanaCoreContracts>,
+  chainId: number
+) {
+  const [chainConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("chain_config"), chainIdSeed(chainId)],
+    program.programId
+  );
 
-    const signatureEvent = (await eventPromises.signature) as any;
-    const signature = extractSignature(signatureEvent);
+  const existing = await program.provider.connection.getAccountInfo(
+    chainConfig
+  );
+  if (existing) {
+    return;
+  }
 
-    // =====================================================
-    // STEP 6: TRY TO SUBMIT (WILL FAIL)
-    // =====================================================
+  const maxFeePerGas = new anchor.BN(
+    ethers.parseUnits(CONFIG.MAX_FEE_PER_GAS, "gwei").toString()
+  );
 
-    console.log("\n📍 Step 6: Attempting to submit transaction...");
+  await program.methods
+    .setChainConfig(new anchor.BN(chainId), {
+      maxGasLimit: new anchor.BN(CONFIG.MAX_GAS_LIMIT),
+      maxFeePerGas,
+      maxPriorityFeePerGas: maxFeePerGas,
+    })
+    .rpc();
 
-    const signedTx = ethers.Transaction.from({
-      type: 2,
-      chainId: CONFIG.SEPOLIA_CHAIN_ID,
-      nonce: txParams.nonce.toNumber(),
-      maxPriorityFeePerGas: BigInt(txParams.maxPriorityFeePerGas.toString()),
-      maxFeePerGas: BigInt(txParams.maxFeePerGas.toString()),
-      gasLimit: BigInt(txParams.gasLimit.toString()),
-      to: CONFIG.USDC_ADDRESS_SEPOLIA,
-      value: BigInt(0),
-      data: callData,
-      signature,
-    });
+  console.log("  ✅ Chain configured:", chainId);
+}
 
-    try {
-      const txHash = await ethUtils.submitTransaction(signedTx);
-      console.log("  🔗 Transaction submitted:", txHash);
-
-      // If nonce was already used, this will fail immediately
-      // If we sent a replacement tx, this will fail with "replacement transaction underpriced"
-      // The server will detect the failure and send error response
-      const receipt = await ethUtils.waitForConfirmation(txHash);
-      console.log("  ⚠️ Transaction unexpectedly succeeded!");
-    } catch (error: any) {
-      console.log("  ✅ Transaction failed as expected!");
-      console.log("  📊 Error:", error.message || error.shortMessage || error);
-      // The server monitoring this transaction will send an error response
-    }
+/**
+ * Encode an EVM chain id as a little-endian PDA seed
+ */
+function chainIdSeed(chainId: number): Buffer {
+  const chainIdBytes = Buffer.alloc(8);
+  chainIdBytes.writeBigUInt64LE(BigInt(chainId));
+  return chainIdBytes;
+}
 
-    // =====================================================
-    // STEP 7: WAIT FOR ERROR RESPONSE
-    // =====================================================
+/**
+ * Get the MPC signer registry entry for a key version
+ */
+function getMpcSigner(
+  program: Program<SolanaCoreContracts>,
+  keyVersion: number
+): anchor.web3.PublicKey {
+  const keyVersionBytes = Buffer.alloc(4);
+  keyVersionBytes.writeUInt32LE(keyVersion);
 
-    console.log("\n📍 Step 7: Waiting for error response...");
+  const [mpcSigner] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("mpc_signer"), keyVersionBytes],
+    program.programId
+  );
 
-    const readEvent = (await eventPromises.readRespond) as any;
-    console.log("  ✅ Got read response!");
-    console.log(
-      "  📊 Response length:",
-      readEvent.serializedOutput.length,
-      "bytes"
-    );
+  return mpcSigner;
+}
 
-    // =====================================================
-    // STEP 8: COMPLETE WITHDRAWAL (REFUND)
-    // =====================================================
+/**
+ * Sync the withdrawal nonce tracker of a key version to the nonce its address is at
+ */
+async function syncNonceTracker(
+  program: Program<SolanaCoreContracts>,
+  nonce: number,
+  keyVersion: number = CONFIG.MPC_KEY_VERSION
+): Promise<anchor.web3.PublicKey> {
+  const keyVersionBytes = Buffer.alloc(4);
+  keyVersionBytes.writeUInt32LE(keyVersion);
 
-    console.log("\n📍 Step 8: Completing withdrawal (expecting refund)...");
+  const [nonceTracker] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("nonce_tracker"),
+      chainIdSeed(CONFIG.SEPOLIA_CHAIN_ID),
+      ethers.getBytes(
+        ethers.keccak256(ethers.toUtf8Bytes(CONFIG.WITHDRAWAL_ROOT_PATH))
+      ),
+      keyVersionBytes,
+    ],
+    program.programId
+  );
 
-    const completeTx = await program.methods
-      .completeWithdrawErc20(
-        requestIdBytes as any,
-        Buffer.from(readEvent.serializedOutput),
-        readEvent.signature
+  const existing = await program.account.nonceTracker.fetchNullable(
+    nonceTracker
+  );
+  if (!existing || existing.nextNonce.toNumber() !== nonce) {
+    await program.methods
+      .syncNonceTracker(
+        new anchor.BN(CONFIG.SEPOLIA_CHAIN_ID),
+        CONFIG.WITHDRAWAL_ROOT_PATH,
+        keyVersion,
+        new anchor.BN(nonce)
       )
-      .accounts({
-        userBalance,
-      })
+      .accounts({ nonceTracker })
       .rpc();
+  }
 
-    console.log("  ✅ Complete withdrawal transaction:", completeTx);
+  return nonceTracker;
+}
 
-    // =====================================================
-    // STEP 9: VERIFY REFUND
-    // =====================================================
+/**
+ * Register a local key as the MPC signer under an unused key version and rotate to it
+ */
+async function registerMockResponder(
+  program: Program<SolanaCoreContracts>,
+  responder: ethers.Wallet
+): Promise<number> {
+  let keyVersion = CONFIG.MPC_KEY_VERSION + 1;
+  while (
+    await program.account.mpcSigner.fetchNullable(
+      getMpcSigner(program, keyVersion)
+    )
+  ) {
+    keyVersion++;
+  }
 
-    console.log("\n📍 Step 9: Verifying balance was refunded...");
+  const responderAddress = Array.from(ethers.getBytes(responder.address));
+  await program.methods
+    .registerMpcSigner(keyVersion, responderAddress, responderAddress)
+    .rpc();
+  await program.methods
+    .rotateMpcKey(keyVersion)
+    .accounts({
+      currentSigner: getMpcSigner(program, CONFIG.MPC_KEY_VERSION),
+      newSigner: getMpcSigner(program, keyVersion),
+    })
+    .rpc();
+
+  return keyVersion;
+}
 
-    const finalBalance = await program.account.userErc20Balance.fetch(
-      userBalance
-    );
+/**
+ * Rotate back to the MPC network's key and stop trusting the mock responder
+ */
+async function restoreMpcKey(
+  program: Program<SolanaCoreContracts>,
+  mockKeyVersion: number
+) {
+  await program.methods
+    .rotateMpcKey(CONFIG.MPC_KEY_VERSION)
+    .accounts({
+      currentSigner: getMpcSigner(program, mockKeyVersion),
+      newSigner: getMpcSigner(program, CONFIG.MPC_KEY_VERSION),
+    })
+    .rpc();
+  await program.methods.revokeMpcSigner(mockKeyVersion).rpc();
+}
 
-    console.log("  💰 Initial balance:", balanceBeforeWithdraw.toString());
-    console.log("  💰 Final balance:", finalBalance.amount.toString());
+/**
+ * Sign a response the way the MPC network does, over keccak256(requestId || output)
+ */
+function signMockResponse(
+  responder: ethers.Wallet,
+  requestId: string,
+  serializedOutput: Buffer
+) {
+  const signature = responder.signingKey.sign(
+    ethers.keccak256(ethers.concat([requestId, serializedOutput]))
+  );
 
-    expect(finalBalance.amount.toString()).to.equal(
-      balanceBeforeWithdraw.toString()
-    );
+  return {
+    bigR: {
+      x: Array.from(ethers.getBytes(signature.r)),
+      y: new Array(32).fill(0),
+    },
+    s: Array.from(ethers.getBytes(signature.s)),
+    recoveryId: signature.yParity,
+  };
+}
 
-    // Cleanup
-    await cleanupEventListeners(chainSignaturesProgram, eventPromises);
+/**
+ * Get the pending withdrawal and request record, keyed by the original request id
+ */
+function getWithdrawalAccounts(
+  program: Program<SolanaCoreContracts>,
+  requestIdBytes: number[]
+) {
+  const [pendingWithdrawal] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_erc20_withdrawal"), Buffer.from(requestIdBytes)],
+    program.programId
+  );
+  const [requestRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("request_record"), Buffer.from(requestIdBytes)],
+    program.programId
+  );
 
-    console.log("\n🎉 Failed withdrawal handled correctly - balance refunded!");
-  });
-});
+  return { pendingWithdrawal, requestRecord };
+}
 
 /**
  * Setup event listeners for chain signatures
@@ -1163,6 +1458,7 @@ async function getDepositAccounts(
     [
       Buffer.from("user_erc20_balance"),
       provider.wallet.publicKey.toBuffer(),
+      chainIdSeed(CONFIG.SEPOLIA_CHAIN_ID),
       Buffer.from(erc20AddressBytes),
     ],
     program.programId